@@ -0,0 +1,137 @@
+//! Embedded HAL Compat conversions
+//! Lossless conversions between equivalent `e-h@0.2.x` and `e-h@1.0.x` types
+//!
+//! As both types are foreign to this crate `From` / `Into` cannot be implemented directly,
+//! so conversions are provided by the [Convert] trait, which is used in the same way as `Into`.
+//!
+//! ```
+//! use embedded_hal_compat::convert::Convert;
+//!
+//! let mode: eh0_2::spi::Mode = eh1_0::spi::MODE_3.convert();
+//! assert!(mode == eh0_2::spi::MODE_3);
+//! ```
+// Copyright 2021 Ryan Kurte
+
+/// Convert a value into the equivalent type from the other `embedded-hal` version
+pub trait Convert<T> {
+    /// Perform the conversion
+    fn convert(self) -> T;
+}
+
+impl Convert<eh1_0::spi::Polarity> for eh0_2::spi::Polarity {
+    fn convert(self) -> eh1_0::spi::Polarity {
+        match self {
+            eh0_2::spi::Polarity::IdleLow => eh1_0::spi::Polarity::IdleLow,
+            eh0_2::spi::Polarity::IdleHigh => eh1_0::spi::Polarity::IdleHigh,
+        }
+    }
+}
+
+impl Convert<eh0_2::spi::Polarity> for eh1_0::spi::Polarity {
+    fn convert(self) -> eh0_2::spi::Polarity {
+        match self {
+            eh1_0::spi::Polarity::IdleLow => eh0_2::spi::Polarity::IdleLow,
+            eh1_0::spi::Polarity::IdleHigh => eh0_2::spi::Polarity::IdleHigh,
+        }
+    }
+}
+
+impl Convert<eh1_0::spi::Phase> for eh0_2::spi::Phase {
+    fn convert(self) -> eh1_0::spi::Phase {
+        match self {
+            eh0_2::spi::Phase::CaptureOnFirstTransition => {
+                eh1_0::spi::Phase::CaptureOnFirstTransition
+            }
+            eh0_2::spi::Phase::CaptureOnSecondTransition => {
+                eh1_0::spi::Phase::CaptureOnSecondTransition
+            }
+        }
+    }
+}
+
+impl Convert<eh0_2::spi::Phase> for eh1_0::spi::Phase {
+    fn convert(self) -> eh0_2::spi::Phase {
+        match self {
+            eh1_0::spi::Phase::CaptureOnFirstTransition => {
+                eh0_2::spi::Phase::CaptureOnFirstTransition
+            }
+            eh1_0::spi::Phase::CaptureOnSecondTransition => {
+                eh0_2::spi::Phase::CaptureOnSecondTransition
+            }
+        }
+    }
+}
+
+impl Convert<eh1_0::spi::Mode> for eh0_2::spi::Mode {
+    fn convert(self) -> eh1_0::spi::Mode {
+        eh1_0::spi::Mode {
+            polarity: self.polarity.convert(),
+            phase: self.phase.convert(),
+        }
+    }
+}
+
+impl Convert<eh0_2::spi::Mode> for eh1_0::spi::Mode {
+    fn convert(self) -> eh0_2::spi::Mode {
+        eh0_2::spi::Mode {
+            polarity: self.polarity.convert(),
+            phase: self.phase.convert(),
+        }
+    }
+}
+
+impl Convert<eh1_0::digital::PinState> for eh0_2::digital::v2::PinState {
+    fn convert(self) -> eh1_0::digital::PinState {
+        match self {
+            eh0_2::digital::v2::PinState::Low => eh1_0::digital::PinState::Low,
+            eh0_2::digital::v2::PinState::High => eh1_0::digital::PinState::High,
+        }
+    }
+}
+
+impl Convert<eh0_2::digital::v2::PinState> for eh1_0::digital::PinState {
+    fn convert(self) -> eh0_2::digital::v2::PinState {
+        match self {
+            eh1_0::digital::PinState::Low => eh0_2::digital::v2::PinState::Low,
+            eh1_0::digital::PinState::High => eh0_2::digital::v2::PinState::High,
+        }
+    }
+}
+
+impl<'a> Convert<eh1_0::i2c::Operation<'a>> for eh0_2::blocking::i2c::Operation<'a> {
+    fn convert(self) -> eh1_0::i2c::Operation<'a> {
+        match self {
+            eh0_2::blocking::i2c::Operation::Read(buff) => eh1_0::i2c::Operation::Read(buff),
+            eh0_2::blocking::i2c::Operation::Write(buff) => eh1_0::i2c::Operation::Write(buff),
+        }
+    }
+}
+
+impl<'a> Convert<eh0_2::blocking::i2c::Operation<'a>> for eh1_0::i2c::Operation<'a> {
+    fn convert(self) -> eh0_2::blocking::i2c::Operation<'a> {
+        match self {
+            eh1_0::i2c::Operation::Read(buff) => eh0_2::blocking::i2c::Operation::Read(buff),
+            eh1_0::i2c::Operation::Write(buff) => eh0_2::blocking::i2c::Operation::Write(buff),
+        }
+    }
+}
+
+/// Reborrow an operation from a slice (ie. using `iter_mut()`)
+impl<'a> Convert<eh1_0::i2c::Operation<'a>> for &'a mut eh0_2::blocking::i2c::Operation<'_> {
+    fn convert(self) -> eh1_0::i2c::Operation<'a> {
+        match self {
+            eh0_2::blocking::i2c::Operation::Read(buff) => eh1_0::i2c::Operation::Read(buff),
+            eh0_2::blocking::i2c::Operation::Write(buff) => eh1_0::i2c::Operation::Write(buff),
+        }
+    }
+}
+
+/// Reborrow an operation from a slice (ie. using `iter_mut()`)
+impl<'a> Convert<eh0_2::blocking::i2c::Operation<'a>> for &'a mut eh1_0::i2c::Operation<'_> {
+    fn convert(self) -> eh0_2::blocking::i2c::Operation<'a> {
+        match self {
+            eh1_0::i2c::Operation::Read(buff) => eh0_2::blocking::i2c::Operation::Read(buff),
+            eh1_0::i2c::Operation::Write(buff) => eh0_2::blocking::i2c::Operation::Write(buff),
+        }
+    }
+}
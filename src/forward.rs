@@ -3,6 +3,18 @@
 // Copyright 2021 Ryan Kurte
 
 use core::marker::PhantomData;
+use core::num::{NonZeroU32, NonZeroUsize};
+
+use crate::markers::{
+    ForwardFullDuplex, ForwardInputPin, ForwardIoPin, ForwardOutputPin, ForwardPolledInput,
+    ForwardWriteOnly,
+};
+#[cfg(target_has_atomic = "8")]
+use crate::{
+    markers::ForwardInterruptInput,
+    notifier::{Claim, NotifierInUse},
+    PinNotifier,
+};
 
 /// Forward compatibility container object.
 /// This is generic over different E-H types and will provide adaption
@@ -28,6 +40,68 @@ impl<T, M> ForwardCompat<T, M> for T {
     }
 }
 
+/// Helper trait to forward pins without spelling out marker types
+/// call `.forward_input()`, `.forward_output()` or `.forward_io()` on `e-h@0.2.x` pins
+pub trait ForwardPinCompat: Sized {
+    /// Create an e-h-c forward compatibility wrapper around an input pin
+    fn forward_input(self) -> Forward<Self, ForwardInputPin>;
+
+    /// Create an e-h-c forward compatibility wrapper around an output pin
+    fn forward_output(self) -> Forward<Self, ForwardOutputPin>;
+
+    /// Create an e-h-c forward compatibility wrapper around an input-output pin
+    fn forward_io(self) -> Forward<Self, ForwardIoPin>;
+
+    /// Create an e-h-c forward compatibility wrapper around an input pin,
+    /// providing async `Wait` by polling the pin level
+    fn forward_polled(self) -> Forward<Polled<Self>, ForwardPolledInput>;
+
+    /// Create an e-h-c forward compatibility wrapper around an input pin,
+    /// providing async `Wait` when woken by the provided [PinNotifier]
+    ///
+    /// This claims the notifier until the pin is dropped or released,
+    /// returning the pin in [NotifierInUse] if the notifier is already claimed
+    #[cfg(target_has_atomic = "8")]
+    fn forward_interrupt(
+        self,
+        notifier: &PinNotifier,
+    ) -> Result<Forward<Notified<'_, Self>, ForwardInterruptInput>, NotifierInUse<Self>>;
+}
+
+/// Blanket [ForwardPinCompat] implementation
+impl<T> ForwardPinCompat for T {
+    fn forward_input(self) -> Forward<T, ForwardInputPin> {
+        Forward::new(self)
+    }
+
+    fn forward_output(self) -> Forward<T, ForwardOutputPin> {
+        Forward::new(self)
+    }
+
+    fn forward_io(self) -> Forward<T, ForwardIoPin> {
+        Forward::new(self)
+    }
+
+    fn forward_polled(self) -> Forward<Polled<T>, ForwardPolledInput> {
+        Forward::new(Polled {
+            pin: self,
+            delay: YieldNow,
+            interval_ns: 0,
+        })
+    }
+
+    #[cfg(target_has_atomic = "8")]
+    fn forward_interrupt(
+        self,
+        notifier: &PinNotifier,
+    ) -> Result<Forward<Notified<'_, T>, ForwardInterruptInput>, NotifierInUse<T>> {
+        match notifier.claim() {
+            Some(claim) => Ok(Forward::new(Notified { pin: self, claim })),
+            None => Err(NotifierInUse(self)),
+        }
+    }
+}
+
 impl<T, M> Forward<T, M> {
     /// Create a new compatibility wrapper object
     pub fn new(inner: T) -> Forward<T, M> {
@@ -53,6 +127,432 @@ impl<T, M> Forward<T, M> {
     }
 }
 
+/// Use a forwarded input-output pin as an input pin
+impl<T> From<Forward<T, ForwardIoPin>> for Forward<T, ForwardInputPin> {
+    fn from(pin: Forward<T, ForwardIoPin>) -> Self {
+        Forward::new(pin.inner)
+    }
+}
+
+/// Use a forwarded input-output pin as an output pin
+impl<T> From<Forward<T, ForwardIoPin>> for Forward<T, ForwardOutputPin> {
+    fn from(pin: Forward<T, ForwardIoPin>) -> Self {
+        Forward::new(pin.inner)
+    }
+}
+
+/// Use a forwarded input pin as an input-output pin
+impl<T> From<Forward<T, ForwardInputPin>> for Forward<T, ForwardIoPin> {
+    fn from(pin: Forward<T, ForwardInputPin>) -> Self {
+        Forward::new(pin.inner)
+    }
+}
+
+/// Use a forwarded output pin as an input-output pin
+impl<T> From<Forward<T, ForwardOutputPin>> for Forward<T, ForwardIoPin> {
+    fn from(pin: Forward<T, ForwardOutputPin>) -> Self {
+        Forward::new(pin.inner)
+    }
+}
+
+/// Polling configuration for [ForwardPolledInput] pins,
+/// see [ForwardPinCompat::forward_polled] and [Forward::with_poll_interval]
+pub struct Polled<T, D = YieldNow> {
+    pin: T,
+    delay: D,
+    interval_ns: u32,
+}
+
+impl<T, D> Polled<T, D> {
+    /// Destroy the polling configuration, returning the pin and delay
+    pub fn release(self) -> (T, D) {
+        (self.pin, self.delay)
+    }
+}
+
+/// Async pseudo-delay that yields once to the executor regardless of duration,
+/// used to poll [ForwardPolledInput] pins as often as the executor allows
+pub struct YieldNow;
+
+impl eh1_0_async::delay::DelayNs for YieldNow {
+    async fn delay_ns(&mut self, _ns: u32) {
+        let mut yielded = false;
+        core::future::poll_fn(|cx| {
+            if yielded {
+                return core::task::Poll::Ready(());
+            }
+            yielded = true;
+            cx.waker().wake_by_ref();
+            core::task::Poll::Pending
+        })
+        .await
+    }
+}
+
+/// Interrupt notification configuration for [ForwardInterruptInput] pins,
+/// see [ForwardPinCompat::forward_interrupt]
+///
+/// Edges are detected by comparing pin levels sampled on each notification,
+/// so pulses shorter than the time taken to wake the waiting task may be missed.
+#[cfg(target_has_atomic = "8")]
+pub struct Notified<'a, T> {
+    pin: T,
+    claim: Claim<'a>,
+}
+
+#[cfg(target_has_atomic = "8")]
+impl<T> Notified<'_, T> {
+    /// Destroy the notification configuration, releasing the notifier and returning the pin
+    pub fn release(self) -> T {
+        self.pin
+    }
+}
+
+/// Forward compatibility wrapper for delays, see [markers](crate::markers) for delay markers
+pub struct ForwardDelay<T, M> {
+    inner: T,
+    _marker: PhantomData<M>,
+}
+
+impl<T, M> ForwardDelay<T, M> {
+    /// Create a new delay compatibility wrapper object
+    pub fn new(inner: T) -> ForwardDelay<T, M> {
+        ForwardDelay {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Fetch a reference to the wrapped object
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Fetch a mutable reference to the wrapped object
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Destroy the compatibility wrapper, returning the wrapped object
+    pub fn unwrap(self) -> T {
+        self.inner
+    }
+}
+
+/// Calibration for sub-microsecond delays, see [ForwardCalibratedDelay]
+pub trait DelayCalibration {
+    /// Number of [DelayCalibration::delay_cycles] cycles per microsecond
+    fn cycles_per_us(&self) -> u32;
+
+    /// Busy-wait for the provided number of cycles,
+    /// by default spinning once per cycle (override to use a hardware cycle counter)
+    fn delay_cycles(&mut self, cycles: u32) {
+        for _ in 0..cycles {
+            core::hint::spin_loop();
+        }
+    }
+}
+
+/// Forward compatibility wrapper providing `DelayNs` with sub-microsecond precision.
+///
+/// Whole microseconds are delayed using the wrapped `e-h@0.2.x` `DelayUs<u32>`, and any
+/// sub-microsecond remainder by busy-waiting using the provided [DelayCalibration].
+pub struct ForwardCalibratedDelay<T, C> {
+    delay: T,
+    calibration: C,
+}
+
+impl<T, C> ForwardCalibratedDelay<T, C> {
+    /// Create a new calibrated delay wrapper
+    pub fn new(delay: T, calibration: C) -> Self {
+        Self { delay, calibration }
+    }
+
+    /// Fetch a reference to the wrapped delay
+    pub fn inner(&self) -> &T {
+        &self.delay
+    }
+
+    /// Fetch a mutable reference to the wrapped delay
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.delay
+    }
+
+    /// Destroy the compatibility wrapper, returning the wrapped delay and calibration
+    pub fn unwrap(self) -> (T, C) {
+        (self.delay, self.calibration)
+    }
+}
+
+/// Forward compatibility wrapper providing blocking and async `DelayNs` using an `e-h@0.2.x`
+/// `CountDown` timer.
+///
+/// Delays are converted to the timer `Time` using the provided `to_time` function, and delays
+/// longer than `max_ns` (the longest period supported by the timer) are split into multiple
+/// count downs.
+pub struct ForwardCountDown<T: eh0_2::timer::CountDown> {
+    timer: T,
+    max_ns: u32,
+    to_time: fn(u32) -> T::Time,
+}
+
+impl<T: eh0_2::timer::CountDown> ForwardCountDown<T> {
+    /// Create a new count down delay wrapper, using `to_time` to convert nanoseconds
+    /// (no greater than `max_ns`) into the timer `Time`
+    pub fn new(timer: T, max_ns: NonZeroU32, to_time: fn(u32) -> T::Time) -> Self {
+        Self {
+            timer,
+            max_ns: max_ns.get(),
+            to_time,
+        }
+    }
+
+    /// Fetch a reference to the wrapped timer
+    pub fn inner(&self) -> &T {
+        &self.timer
+    }
+
+    /// Fetch a mutable reference to the wrapped timer
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.timer
+    }
+
+    /// Destroy the compatibility wrapper, returning the wrapped timer
+    pub fn unwrap(self) -> T {
+        self.timer
+    }
+}
+
+/// Helper trait to forward SPI buses with a [ForwardSpiConfig] or `FullDuplex` implementation
+/// call `.forward_with(config)` or `.forward_full_duplex()` on `e-h@0.2.x` SPI buses
+pub trait ForwardSpiCompat: Sized {
+    /// Create an e-h-c forward compatibility wrapper around an SPI bus using the provided config
+    fn forward_with(self, config: ForwardSpiConfig<Self>) -> ForwardSpi<Self>
+    where
+        Self: eh0_2::blocking::spi::Transfer<u8>;
+
+    /// Create an e-h-c forward compatibility wrapper around an SPI bus
+    /// implementing only the non-blocking `FullDuplex` trait
+    fn forward_full_duplex(self) -> Forward<FullDuplexBus<Self>, ForwardFullDuplex>;
+
+    /// Create an e-h-c forward compatibility wrapper around an SPI bus
+    /// implementing only the blocking `Write` trait
+    fn forward_write_only(self) -> Forward<WriteOnlyBus<Self>, ForwardWriteOnly>;
+}
+
+/// Blanket [ForwardSpiCompat] implementation
+impl<T> ForwardSpiCompat for T {
+    fn forward_with(self, config: ForwardSpiConfig<T>) -> ForwardSpi<T>
+    where
+        T: eh0_2::blocking::spi::Transfer<u8>,
+    {
+        ForwardSpi::new(self, config)
+    }
+
+    fn forward_full_duplex(self) -> Forward<FullDuplexBus<T>, ForwardFullDuplex> {
+        Forward::new(FullDuplexBus { bus: self })
+    }
+
+    fn forward_write_only(self) -> Forward<WriteOnlyBus<T>, ForwardWriteOnly> {
+        Forward::new(WriteOnlyBus { bus: self })
+    }
+}
+
+/// Word by word adaptor for [ForwardFullDuplex] SPI buses,
+/// see [ForwardSpiCompat::forward_full_duplex]
+pub struct FullDuplexBus<T> {
+    bus: T,
+}
+
+impl<T> FullDuplexBus<T> {
+    /// Destroy the adaptor, returning the bus
+    pub fn release(self) -> T {
+        self.bus
+    }
+}
+
+impl<T> Forward<FullDuplexBus<T>, ForwardFullDuplex> {
+    /// Destroy the compatibility wrapper, returning the wrapped bus
+    pub fn release(self) -> T {
+        self.inner.bus
+    }
+}
+
+/// Flush hook for forwarded SPI buses, see [ForwardSpiConfig]
+pub type FlushFn<T> = fn(&mut T) -> Result<(), <T as eh0_2::blocking::spi::Transfer<u8>>::Error>;
+
+/// Adaptor for [ForwardWriteOnly] SPI buses, see [ForwardSpiCompat::forward_write_only]
+pub struct WriteOnlyBus<T> {
+    bus: T,
+}
+
+impl<T> WriteOnlyBus<T> {
+    /// Destroy the adaptor, returning the bus
+    pub fn release(self) -> T {
+        self.bus
+    }
+}
+
+impl<T> Forward<WriteOnlyBus<T>, ForwardWriteOnly> {
+    /// Destroy the compatibility wrapper, returning the wrapped bus
+    pub fn release(self) -> T {
+        self.inner.bus
+    }
+}
+
+/// Error type for [ForwardWriteOnly] SPI buses
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum WriteOnlyError<E> {
+    /// Error from the wrapped bus
+    Bus(E),
+    /// Reads are not supported by write-only buses
+    Unsupported,
+}
+
+/// Configuration for forwarded SPI buses, see [ForwardSpiCompat::forward_with]
+pub struct ForwardSpiConfig<T: eh0_2::blocking::spi::Transfer<u8>> {
+    /// Word clocked out while reading or padding transfers (ie. `0xFF` for SD cards)
+    pub fill: u8,
+    /// Hook called by `SpiBus::flush` to wait for the bus to become idle
+    pub flush: Option<FlushFn<T>>,
+    /// Maximum number of words per `e-h@0.2.x` operation (ie. for DMA length limits)
+    pub max_chunk: Option<NonZeroUsize>,
+}
+
+impl<T: eh0_2::blocking::spi::Transfer<u8>> Default for ForwardSpiConfig<T> {
+    fn default() -> Self {
+        Self {
+            fill: 0x00,
+            flush: None,
+            max_chunk: None,
+        }
+    }
+}
+
+/// Forward compatibility wrapper providing `SpiBus` using an `e-h@0.2.x` SPI bus
+/// with a [ForwardSpiConfig]
+pub struct ForwardSpi<T: eh0_2::blocking::spi::Transfer<u8>> {
+    inner: T,
+    config: ForwardSpiConfig<T>,
+}
+
+impl<T: eh0_2::blocking::spi::Transfer<u8>> ForwardSpi<T> {
+    /// Create a new configured SPI wrapper
+    pub fn new(inner: T, config: ForwardSpiConfig<T>) -> Self {
+        Self { inner, config }
+    }
+
+    /// Fetch a reference to the wrapped bus
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Fetch a mutable reference to the wrapped bus
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Destroy the compatibility wrapper, returning the wrapped bus
+    pub fn unwrap(self) -> T {
+        self.inner
+    }
+}
+
+/// Forward compatibility wrapper providing `SpiDevice` using an `e-h@0.2.x` SPI bus,
+/// chip select `OutputPin` and `DelayUs<u32>` delay.
+///
+/// Each transaction asserts (drives low) the chip select, runs all operations
+/// and then de-asserts the chip select, even if an operation fails.
+pub struct ForwardSpiDevice<B, CS, D> {
+    bus: B,
+    cs: CS,
+    delay: D,
+}
+
+impl<B, CS, D> ForwardSpiDevice<B, CS, D> {
+    /// Create a new SPI device wrapper
+    pub fn new(bus: B, cs: CS, delay: D) -> Self {
+        Self { bus, cs, delay }
+    }
+
+    /// Fetch a reference to the wrapped bus
+    pub fn inner(&self) -> &B {
+        &self.bus
+    }
+
+    /// Fetch a mutable reference to the wrapped bus
+    pub fn inner_mut(&mut self) -> &mut B {
+        &mut self.bus
+    }
+
+    /// Destroy the compatibility wrapper, returning the wrapped bus, chip select and delay
+    pub fn unwrap(self) -> (B, CS, D) {
+        (self.bus, self.cs, self.delay)
+    }
+}
+
+/// Forward compatibility wrapper providing `SpiDevice` using an `e-h@0.2.x` SPI bus
+/// implementing `Transactional<u8>` and a chip select `OutputPin`.
+///
+/// Each transaction asserts (drives low) the chip select, runs all operations using a single
+/// `Transactional::exec` call and then de-asserts the chip select, even if the call fails.
+///
+/// Operations are batched on the stack, so each transaction may use at most `N` `e-h@0.2.x`
+/// operations (a `Transfer` with unequal buffer lengths uses up to three), and larger transactions
+/// fail with [TransactionalError::TooManyOperations] without running. Delays cannot be expressed
+/// as `e-h@0.2.x` operations, so transactions containing `Operation::DelayNs` fail with
+/// [TransactionalError::Unsupported] without running.
+pub struct ForwardTransactionalDevice<B, CS, const N: usize> {
+    bus: B,
+    cs: CS,
+}
+
+impl<B, CS, const N: usize> ForwardTransactionalDevice<B, CS, N> {
+    /// Create a new transactional SPI device wrapper
+    pub fn new(bus: B, cs: CS) -> Self {
+        Self { bus, cs }
+    }
+
+    /// Fetch a reference to the wrapped bus
+    pub fn inner(&self) -> &B {
+        &self.bus
+    }
+
+    /// Fetch a mutable reference to the wrapped bus
+    pub fn inner_mut(&mut self) -> &mut B {
+        &mut self.bus
+    }
+
+    /// Destroy the compatibility wrapper, returning the wrapped bus and chip select
+    pub fn unwrap(self) -> (B, CS) {
+        (self.bus, self.cs)
+    }
+}
+
+/// Bus error type for [ForwardTransactionalDevice], reported via [SpiDeviceError::Bus]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum TransactionalError<E> {
+    /// Error from the wrapped bus
+    Bus(E),
+    /// The transaction contains operations that cannot be run in a single `exec` call
+    /// (ie. `Operation::DelayNs`)
+    Unsupported,
+    /// The transaction needs more `e-h@0.2.x` operations than the device capacity
+    TooManyOperations,
+}
+
+/// Error type for [ForwardSpiDevice] and [ForwardTransactionalDevice]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum SpiDeviceError<B, P> {
+    /// Error from the wrapped bus
+    Bus(B),
+    /// Error from the wrapped chip select pin
+    ChipSelect(P),
+}
+
 /// Fake SPI error type for forward compatibility.
 ///
 /// This fulfils error trait bounds but `.kind()` always returns `Other`
@@ -62,11 +562,26 @@ pub struct ForwardError<E>(pub E);
 
 // note that implementations over Forward cannot be generic over word type
 // etc. due to orphan rules (ie. what happens if someone else defines a word type?)
+// so wider SPI words are selected using markers
+//
+// markers for traits a type commonly implements alongside those used by `Forward<T>`
+// (ie. `FullDuplex` with `Transfer` / `Write`, or several delay traits) instead wrap
+// an adaptor type (`FullDuplexBus`, `WriteOnlyBus`) or use `ForwardDelay`, as impls
+// over `Forward<T, Marker>` would make unannotated `.forward()` calls ambiguous
 
 // Digital / GPIOs
 mod digital {
+    use super::Polled;
     use super::{Forward, ForwardError};
-    use crate::markers::{ForwardInputPin, ForwardIoPin, ForwardOutputPin};
+    #[cfg(target_has_atomic = "8")]
+    use super::{ForwardInterruptInput, Notified};
+    use crate::convert::Convert;
+    use crate::markers::{
+        ForwardInputPin, ForwardIoPin, ForwardOutputPin, ForwardPolledInput,
+        ForwardToggleableOutputPin, ForwardV1InputPin, ForwardV1OutputPin,
+    };
+    use core::convert::Infallible;
+    use core::task::Poll;
 
     impl<E: core::fmt::Debug> eh1_0::digital::Error for ForwardError<E> {
         fn kind(&self) -> eh1_0::digital::ErrorKind {
@@ -154,6 +669,22 @@ mod digital {
         }
     }
 
+    impl<T, E> eh1_0::digital::StatefulOutputPin for Forward<T, ForwardOutputPin>
+    where
+        T: eh0_2::digital::v2::StatefulOutputPin<Error = E>,
+        E: core::fmt::Debug,
+    {
+        /// Is the pin in drive high mode?
+        fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+            self.inner.is_set_high().map_err(ForwardError)
+        }
+
+        /// Is the pin in drive low mode?
+        fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+            self.inner.is_set_low().map_err(ForwardError)
+        }
+    }
+
     impl<T, E> eh1_0_async::digital::Wait for Forward<T, ForwardOutputPin>
     where
         T: eh0_2::digital::v2::OutputPin<Error = E> + eh1_0_async::digital::Wait<Error = E>,
@@ -226,6 +757,23 @@ mod digital {
         }
     }
 
+    impl<T, E> eh1_0::digital::StatefulOutputPin for Forward<T, ForwardIoPin>
+    where
+        T: eh0_2::digital::v2::InputPin<Error = E>
+            + eh0_2::digital::v2::StatefulOutputPin<Error = E>,
+        E: core::fmt::Debug,
+    {
+        /// Is the pin in drive high mode?
+        fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+            self.inner.is_set_high().map_err(ForwardError)
+        }
+
+        /// Is the pin in drive low mode?
+        fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+            self.inner.is_set_low().map_err(ForwardError)
+        }
+    }
+
     impl<T, E> eh1_0_async::digital::Wait for Forward<T, ForwardIoPin>
     where
         T: eh0_2::digital::v2::InputPin<Error = E>
@@ -259,84 +807,1104 @@ mod digital {
             self.inner.wait_for_any_edge().await.map_err(ForwardError)
         }
     }
-}
 
-/// Delays (blocking)
-mod delay {
-    use super::Forward;
+    impl<T, E> eh1_0::digital::ErrorType for Forward<T, ForwardToggleableOutputPin>
+    where
+        T: eh0_2::digital::v2::StatefulOutputPin<Error = E>
+            + eh0_2::digital::v2::ToggleableOutputPin<Error = E>,
+        E: core::fmt::Debug,
+    {
+        type Error = super::ForwardError<E>;
+    }
 
-    impl<T> eh1_0::delay::DelayNs for Forward<T>
+    impl<T, E> eh1_0::digital::OutputPin for Forward<T, ForwardToggleableOutputPin>
     where
-        T: eh0_2::blocking::delay::DelayUs<u32>,
+        T: eh0_2::digital::v2::StatefulOutputPin<Error = E>
+            + eh0_2::digital::v2::ToggleableOutputPin<Error = E>,
+        E: core::fmt::Debug,
     {
-        fn delay_ns(&mut self, ns: u32) {
-            self.inner.delay_us(ns.div_ceil(1000))
+        /// Set the output as high
+        fn set_high(&mut self) -> Result<(), Self::Error> {
+            self.inner.set_high().map_err(ForwardError)
         }
 
-        fn delay_us(&mut self, us: u32) {
-            self.inner.delay_us(us)
+        /// Set the output as low
+        fn set_low(&mut self) -> Result<(), Self::Error> {
+            self.inner.set_low().map_err(ForwardError)
         }
     }
-}
 
-/// SPI (blocking)
-mod spi {
-    use super::{Forward, ForwardError};
+    impl<T, E> eh1_0::digital::StatefulOutputPin for Forward<T, ForwardToggleableOutputPin>
+    where
+        T: eh0_2::digital::v2::StatefulOutputPin<Error = E>
+            + eh0_2::digital::v2::ToggleableOutputPin<Error = E>,
+        E: core::fmt::Debug,
+    {
+        /// Is the pin in drive high mode?
+        fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+            self.inner.is_set_high().map_err(ForwardError)
+        }
 
-    impl<E: core::fmt::Debug> eh1_0::spi::Error for ForwardError<E> {
-        fn kind(&self) -> eh1_0::spi::ErrorKind {
-            eh1_0::spi::ErrorKind::Other
+        /// Is the pin in drive low mode?
+        fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+            self.inner.is_set_low().map_err(ForwardError)
+        }
+
+        /// Toggle the pin using the native `ToggleableOutputPin` implementation
+        fn toggle(&mut self) -> Result<(), Self::Error> {
+            self.inner.toggle().map_err(ForwardError)
         }
     }
 
-    impl<T, E> eh1_0::spi::ErrorType for Forward<T>
+    #[allow(deprecated)]
+    impl<T> eh1_0::digital::ErrorType for Forward<T, ForwardV1InputPin>
     where
-        T: eh0_2::blocking::spi::Transfer<u8, Error = E>,
-        E: core::fmt::Debug,
+        T: eh0_2::digital::v1::InputPin,
+    {
+        type Error = Infallible;
+    }
+
+    #[allow(deprecated)]
+    impl<T> eh1_0::digital::InputPin for Forward<T, ForwardV1InputPin>
+    where
+        T: eh0_2::digital::v1::InputPin,
+    {
+        /// Is the input pin high?
+        fn is_high(&mut self) -> Result<bool, Self::Error> {
+            Ok(self.inner.is_high())
+        }
+
+        /// Is the input pin low?
+        fn is_low(&mut self) -> Result<bool, Self::Error> {
+            Ok(self.inner.is_low())
+        }
+    }
+
+    #[allow(deprecated)]
+    impl<T> eh1_0::digital::ErrorType for Forward<T, ForwardV1OutputPin>
+    where
+        T: eh0_2::digital::v1::OutputPin,
+    {
+        type Error = Infallible;
+    }
+
+    #[allow(deprecated)]
+    impl<T> eh1_0::digital::OutputPin for Forward<T, ForwardV1OutputPin>
+    where
+        T: eh0_2::digital::v1::OutputPin,
+    {
+        /// Set the output as high
+        fn set_high(&mut self) -> Result<(), Self::Error> {
+            self.inner.set_high();
+            Ok(())
+        }
+
+        /// Set the output as low
+        fn set_low(&mut self) -> Result<(), Self::Error> {
+            self.inner.set_low();
+            Ok(())
+        }
+    }
+
+    impl<T, D> Forward<Polled<T, D>, ForwardPolledInput> {
+        /// Pause for `interval_ns` between samples using the provided async delay,
+        /// rather than yielding to the executor
+        pub fn with_poll_interval<D2>(
+            self,
+            delay: D2,
+            interval_ns: u32,
+        ) -> Forward<Polled<T, D2>, ForwardPolledInput> {
+            Forward::new(Polled {
+                pin: self.inner.pin,
+                delay,
+                interval_ns,
+            })
+        }
+    }
+
+    impl<T, D, E> Forward<Polled<T, D>, ForwardPolledInput>
+    where
+        T: eh0_2::digital::v2::InputPin<Error = E>,
+        D: eh1_0_async::delay::DelayNs,
+        E: core::fmt::Debug,
+    {
+        /// Poll until the pin reaches the provided level
+        async fn poll_for_level(&mut self, high: bool) -> Result<(), ForwardError<E>> {
+            while self.inner.pin.is_high().map_err(ForwardError)? != high {
+                self.inner.delay.delay_ns(self.inner.interval_ns).await;
+            }
+            Ok(())
+        }
+
+        /// Poll until the pin changes to the provided level, or changes at all if `None`
+        async fn poll_for_edge(&mut self, high: Option<bool>) -> Result<(), ForwardError<E>> {
+            let mut last = self.inner.pin.is_high().map_err(ForwardError)?;
+            loop {
+                self.inner.delay.delay_ns(self.inner.interval_ns).await;
+                let level = self.inner.pin.is_high().map_err(ForwardError)?;
+                if level != last && high.map_or(true, |high| high == level) {
+                    return Ok(());
+                }
+                last = level;
+            }
+        }
+    }
+
+    impl<T, D, E> eh1_0::digital::ErrorType for Forward<Polled<T, D>, ForwardPolledInput>
+    where
+        T: eh0_2::digital::v2::InputPin<Error = E>,
+        D: eh1_0_async::delay::DelayNs,
+        E: core::fmt::Debug,
+    {
+        type Error = super::ForwardError<E>;
+    }
+
+    impl<T, D, E> eh1_0::digital::InputPin for Forward<Polled<T, D>, ForwardPolledInput>
+    where
+        T: eh0_2::digital::v2::InputPin<Error = E>,
+        D: eh1_0_async::delay::DelayNs,
+        E: core::fmt::Debug,
+    {
+        /// Is the input pin high?
+        fn is_high(&mut self) -> Result<bool, Self::Error> {
+            self.inner.pin.is_high().map_err(ForwardError)
+        }
+
+        /// Is the input pin low?
+        fn is_low(&mut self) -> Result<bool, Self::Error> {
+            self.inner.pin.is_low().map_err(ForwardError)
+        }
+    }
+
+    impl<T, D, E> eh1_0_async::digital::Wait for Forward<Polled<T, D>, ForwardPolledInput>
+    where
+        T: eh0_2::digital::v2::InputPin<Error = E>,
+        D: eh1_0_async::delay::DelayNs,
+        E: core::fmt::Debug,
+    {
+        async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+            self.poll_for_level(true).await
+        }
+
+        async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+            self.poll_for_level(false).await
+        }
+
+        async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+            self.poll_for_edge(Some(true)).await
+        }
+
+        async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+            self.poll_for_edge(Some(false)).await
+        }
+
+        async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+            self.poll_for_edge(None).await
+        }
+    }
+
+    #[cfg(target_has_atomic = "8")]
+    impl<T, E> Forward<Notified<'_, T>, ForwardInterruptInput>
+    where
+        T: eh0_2::digital::v2::InputPin<Error = E>,
+        E: core::fmt::Debug,
+    {
+        /// Wait for a notification until the pin reaches the provided level
+        async fn notified_level(&mut self, high: bool) -> Result<(), ForwardError<E>> {
+            let Notified { pin, claim } = &self.inner;
+            core::future::poll_fn(|cx| {
+                // Register before sampling so notifications are not lost
+                claim.register(cx.waker());
+                match pin.is_high() {
+                    Ok(level) if level == high => Poll::Ready(Ok(())),
+                    Ok(_) => Poll::Pending,
+                    Err(e) => Poll::Ready(Err(ForwardError(e))),
+                }
+            })
+            .await
+        }
+
+        /// Wait for a notification until the pin changes to the provided level,
+        /// or changes at all if `None`
+        async fn notified_edge(&mut self, high: Option<bool>) -> Result<(), ForwardError<E>> {
+            let Notified { pin, claim } = &self.inner;
+            let mut last = None;
+            core::future::poll_fn(|cx| {
+                claim.register(cx.waker());
+                let level = match pin.is_high() {
+                    Ok(level) => level,
+                    Err(e) => return Poll::Ready(Err(ForwardError(e))),
+                };
+                match last.replace(level) {
+                    Some(last) if last != level && high.map_or(true, |high| high == level) => {
+                        Poll::Ready(Ok(()))
+                    }
+                    _ => Poll::Pending,
+                }
+            })
+            .await
+        }
+    }
+
+    #[cfg(target_has_atomic = "8")]
+    impl<T, E> eh1_0::digital::ErrorType for Forward<Notified<'_, T>, ForwardInterruptInput>
+    where
+        T: eh0_2::digital::v2::InputPin<Error = E>,
+        E: core::fmt::Debug,
+    {
+        type Error = super::ForwardError<E>;
+    }
+
+    #[cfg(target_has_atomic = "8")]
+    impl<T, E> eh1_0::digital::InputPin for Forward<Notified<'_, T>, ForwardInterruptInput>
+    where
+        T: eh0_2::digital::v2::InputPin<Error = E>,
+        E: core::fmt::Debug,
+    {
+        /// Is the input pin high?
+        fn is_high(&mut self) -> Result<bool, Self::Error> {
+            self.inner.pin.is_high().map_err(ForwardError)
+        }
+
+        /// Is the input pin low?
+        fn is_low(&mut self) -> Result<bool, Self::Error> {
+            self.inner.pin.is_low().map_err(ForwardError)
+        }
+    }
+
+    #[cfg(target_has_atomic = "8")]
+    impl<T, E> eh1_0_async::digital::Wait for Forward<Notified<'_, T>, ForwardInterruptInput>
+    where
+        T: eh0_2::digital::v2::InputPin<Error = E>,
+        E: core::fmt::Debug,
+    {
+        async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+            self.notified_level(true).await
+        }
+
+        async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+            self.notified_level(false).await
+        }
+
+        async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+            self.notified_edge(Some(true)).await
+        }
+
+        async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+            self.notified_edge(Some(false)).await
+        }
+
+        async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+            self.notified_edge(None).await
+        }
+    }
+
+    impl<T, M> Forward<T, M> {
+        /// Convert a forwarded `IoPin` into a forwarded input pin
+        pub fn into_input_pin<I, O, E>(self) -> Result<Forward<I, ForwardInputPin>, ForwardError<E>>
+        where
+            T: eh0_2::digital::v2::IoPin<I, O, Error = E>,
+            I: eh0_2::digital::v2::InputPin + eh0_2::digital::v2::IoPin<I, O>,
+            O: eh0_2::digital::v2::OutputPin + eh0_2::digital::v2::IoPin<I, O>,
+        {
+            self.inner
+                .into_input_pin()
+                .map(Forward::new)
+                .map_err(ForwardError)
+        }
+
+        /// Convert a forwarded `IoPin` into a forwarded output pin with the provided initial state
+        pub fn into_output_pin<I, O, E>(
+            self,
+            state: eh1_0::digital::PinState,
+        ) -> Result<Forward<O, ForwardOutputPin>, ForwardError<E>>
+        where
+            T: eh0_2::digital::v2::IoPin<I, O, Error = E>,
+            I: eh0_2::digital::v2::InputPin + eh0_2::digital::v2::IoPin<I, O>,
+            O: eh0_2::digital::v2::OutputPin + eh0_2::digital::v2::IoPin<I, O>,
+        {
+            self.inner
+                .into_output_pin(state.convert())
+                .map(Forward::new)
+                .map_err(ForwardError)
+        }
+    }
+}
+
+/// Delays (blocking)
+mod delay {
+    use super::{
+        DelayCalibration, Forward, ForwardCalibratedDelay, ForwardCountDown, ForwardDelay,
+    };
+    use crate::markers::{ForwardDelayMs, ForwardDelayUs16, ForwardDelayUs8, ForwardDelayUsMs};
+
+    /// Split a delay into chunks no larger than `max`
+    fn chunked(mut total: u32, max: u32, mut f: impl FnMut(u32)) {
+        while total > 0 {
+            let chunk = total.min(max);
+            f(chunk);
+            total -= chunk;
+        }
+    }
+
+    impl<T> eh1_0::delay::DelayNs for Forward<T>
+    where
+        T: eh0_2::blocking::delay::DelayUs<u32>,
+    {
+        fn delay_ns(&mut self, ns: u32) {
+            self.inner.delay_us(ns.div_ceil(1000))
+        }
+
+        fn delay_us(&mut self, us: u32) {
+            self.inner.delay_us(us)
+        }
+    }
+
+    impl<T> eh1_0::delay::DelayNs for ForwardDelay<T, ForwardDelayUsMs>
+    where
+        T: eh0_2::blocking::delay::DelayUs<u32> + eh0_2::blocking::delay::DelayMs<u32>,
+    {
+        fn delay_ns(&mut self, ns: u32) {
+            self.inner.delay_us(ns.div_ceil(1000))
+        }
+
+        fn delay_us(&mut self, us: u32) {
+            self.inner.delay_us(us)
+        }
+
+        fn delay_ms(&mut self, ms: u32) {
+            self.inner.delay_ms(ms)
+        }
+    }
+
+    impl<T> eh1_0::delay::DelayNs for ForwardCountDown<T>
+    where
+        T: eh0_2::timer::CountDown,
+    {
+        fn delay_ns(&mut self, ns: u32) {
+            // Restart the timer for delays longer than one period
+            chunked(ns, self.max_ns, |ns| {
+                self.timer.start((self.to_time)(ns));
+                // CountDown::wait is infallible (Void error)
+                let _ = nb::block!(self.timer.wait());
+            })
+        }
+    }
+
+    impl<T, C> eh1_0::delay::DelayNs for ForwardCalibratedDelay<T, C>
+    where
+        T: eh0_2::blocking::delay::DelayUs<u32>,
+        C: DelayCalibration,
+    {
+        fn delay_ns(&mut self, ns: u32) {
+            // Whole microseconds use the wrapped delay, the remainder is spun
+            let (us, rem) = (ns / 1000, ns % 1000);
+            if us > 0 {
+                self.delay.delay_us(us)
+            }
+            if rem > 0 {
+                let cycles = (rem as u64 * self.calibration.cycles_per_us() as u64).div_ceil(1000);
+                self.calibration.delay_cycles(cycles as u32)
+            }
+        }
+
+        fn delay_us(&mut self, us: u32) {
+            self.delay.delay_us(us)
+        }
+    }
+
+    /// Delays are rounded up to whole milliseconds
+    impl<T> eh1_0::delay::DelayNs for ForwardDelay<T, ForwardDelayMs>
+    where
+        T: eh0_2::blocking::delay::DelayMs<u32>,
+    {
+        fn delay_ns(&mut self, ns: u32) {
+            self.inner.delay_ms(ns.div_ceil(1_000_000))
+        }
+
+        fn delay_us(&mut self, us: u32) {
+            self.inner.delay_ms(us.div_ceil(1000))
+        }
+
+        fn delay_ms(&mut self, ms: u32) {
+            self.inner.delay_ms(ms)
+        }
+    }
+
+    /// Delays are split into chunks of up to `u16::MAX` microseconds
+    impl<T> eh1_0::delay::DelayNs for ForwardDelay<T, ForwardDelayUs16>
+    where
+        T: eh0_2::blocking::delay::DelayUs<u16>,
+    {
+        fn delay_ns(&mut self, ns: u32) {
+            self.delay_us(ns.div_ceil(1000))
+        }
+
+        fn delay_us(&mut self, us: u32) {
+            chunked(us, u16::MAX as u32, |us| self.inner.delay_us(us as u16))
+        }
+
+        fn delay_ms(&mut self, ms: u32) {
+            // Whole milliseconds per chunk to avoid overflowing the microsecond count
+            chunked(ms, u16::MAX as u32 / 1000, |ms| {
+                self.inner.delay_us((ms * 1000) as u16)
+            })
+        }
+    }
+
+    /// Delays are split into chunks of up to `u8::MAX` microseconds
+    impl<T> eh1_0::delay::DelayNs for ForwardDelay<T, ForwardDelayUs8>
+    where
+        T: eh0_2::blocking::delay::DelayUs<u8>,
+    {
+        fn delay_ns(&mut self, ns: u32) {
+            self.delay_us(ns.div_ceil(1000))
+        }
+
+        fn delay_us(&mut self, us: u32) {
+            chunked(us, u8::MAX as u32, |us| self.inner.delay_us(us as u8))
+        }
+
+        fn delay_ms(&mut self, ms: u32) {
+            // Four 250us chunks per millisecond to avoid overflowing the microsecond count
+            for _ in 0..ms {
+                for _ in 0..4 {
+                    self.inner.delay_us(250)
+                }
+            }
+        }
+    }
+}
+
+/// Delays (async)
+mod delay_async {
+    use super::ForwardCountDown;
+    use core::task::Poll;
+
+    /// Count downs are polled without blocking the executor,
+    /// note the task is immediately re-woken so the executor will spin while waiting
+    impl<T> eh1_0_async::delay::DelayNs for ForwardCountDown<T>
+    where
+        T: eh0_2::timer::CountDown,
+    {
+        async fn delay_ns(&mut self, mut ns: u32) {
+            // Restart the timer for delays longer than one period
+            while ns > 0 {
+                let chunk = ns.min(self.max_ns);
+                self.timer.start((self.to_time)(chunk));
+                core::future::poll_fn(|cx| match self.timer.wait() {
+                    Ok(()) => Poll::Ready(()),
+                    Err(nb::Error::WouldBlock) => {
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    }
+                    Err(nb::Error::Other(void)) => match void {},
+                })
+                .await;
+                ns -= chunk;
+            }
+        }
+    }
+}
+
+/// SPI (blocking)
+mod spi {
+    use super::{
+        Forward, ForwardError, ForwardSpi, ForwardSpiDevice, ForwardTransactionalDevice,
+        FullDuplexBus, SpiDeviceError, TransactionalError, WriteOnlyBus, WriteOnlyError,
+    };
+    use crate::markers::{ForwardFullDuplex, ForwardSpiU16, ForwardSpiU32, ForwardWriteOnly};
+    use core::fmt::Debug;
+    use core::num::NonZeroUsize;
+    use eh0_2::blocking::spi::{Transfer, Write};
+    use eh0_2::spi::FullDuplex;
+    use eh1_0::spi::Operation;
+
+    impl<E: core::fmt::Debug> eh1_0::spi::Error for ForwardError<E> {
+        fn kind(&self) -> eh1_0::spi::ErrorKind {
+            eh1_0::spi::ErrorKind::Other
+        }
+    }
+
+    /// Maximum words per `e-h@0.2.x` operation
+    fn chunk_len(max_chunk: Option<NonZeroUsize>) -> usize {
+        max_chunk.map_or(usize::MAX, NonZeroUsize::get)
+    }
+
+    /// Transfer a chunk in place, copying back received words if
+    /// the HAL returns a different buffer (ie. an internal DMA buffer)
+    fn transfer_chunk<T, W, E>(inner: &mut T, words: &mut [W]) -> Result<(), E>
+    where
+        T: Transfer<W, Error = E>,
+        W: Copy,
+    {
+        let len = words.len();
+
+        let received = inner.transfer(words)?;
+        let src = received.as_ptr();
+        let n = received.len().min(len);
+
+        // Take the destination after the transfer, as reborrowing `words` for the call
+        // invalidates any pointer derived from it beforehand
+        let dst = words.as_mut_ptr();
+
+        match (src as usize).wrapping_sub(dst as usize) / core::mem::size_of::<W>() {
+            // Received in place
+            0 => (),
+            // Received into a sub-slice of the input buffer
+            offset if offset < len => words.copy_within(offset..offset + n, 0),
+            // Received into a separate buffer
+            _ => {
+                // Safety: `src` is valid for `n` reads and cannot overlap the exclusively
+                // borrowed input buffer, and `dst` is valid for `len >= n` writes
+                unsafe { core::ptr::copy_nonoverlapping(src, dst, n) }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read words, clocking out the fill word
+    fn read<T, W, E>(inner: &mut T, words: &mut [W], fill: W, max: usize) -> Result<(), E>
+    where
+        T: Transfer<W, Error = E>,
+        W: Copy,
+    {
+        for chunk in words.chunks_mut(max) {
+            chunk.fill(fill);
+            transfer_chunk(inner, chunk)?;
+        }
+        Ok(())
+    }
+
+    /// Write words, discarding reads
+    fn write<T, W, E>(inner: &mut T, words: &[W], max: usize) -> Result<(), E>
+    where
+        T: Write<W, Error = E>,
+    {
+        for chunk in words.chunks(max) {
+            inner.write(chunk)?;
+        }
+        Ok(())
+    }
+
+    /// Transfer words in place
+    fn transfer_in_place<T, W, E>(inner: &mut T, words: &mut [W], max: usize) -> Result<(), E>
+    where
+        T: Transfer<W, Error = E>,
+        W: Copy,
+    {
+        for chunk in words.chunks_mut(max) {
+            transfer_chunk(inner, chunk)?;
+        }
+        Ok(())
+    }
+
+    /// Transfer words, padding writes with the fill word and discarding surplus reads
+    fn transfer<T, W, E>(
+        inner: &mut T,
+        read: &mut [W],
+        write: &[W],
+        fill: W,
+        max: usize,
+    ) -> Result<(), E>
+    where
+        T: Transfer<W, Error = E> + Write<W, Error = E>,
+        W: Copy,
+    {
+        let common = read.len().min(write.len());
+        let (read, read_extra) = read.split_at_mut(common);
+        let (write, write_extra) = write.split_at(common);
+
+        // Transfer the overlapping words in place
+        read.copy_from_slice(write);
+        transfer_in_place(inner, read, max)?;
+
+        // Write surplus words discarding reads, or read surplus words sending the fill word
+        self::write(inner, write_extra, max)?;
+        self::read(inner, read_extra, fill, max)
+    }
+
+    impl<T, E> eh1_0::spi::ErrorType for Forward<T>
+    where
+        T: Transfer<u8, Error = E>,
+        E: core::fmt::Debug,
     {
         type Error = ForwardError<E>;
     }
 
     impl<T, E> eh1_0::spi::SpiBus<u8> for Forward<T>
     where
-        T: eh0_2::blocking::spi::Transfer<u8, Error = E>
-            + eh0_2::blocking::spi::Write<u8, Error = E>,
+        T: Transfer<u8, Error = E> + Write<u8, Error = E>,
+        E: core::fmt::Debug,
+    {
+        fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+            // Send empty bytes
+            read(&mut self.inner, words, 0x00, usize::MAX).map_err(ForwardError)
+        }
+
+        fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+            write(&mut self.inner, words, usize::MAX).map_err(ForwardError)
+        }
+
+        fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+            transfer(&mut self.inner, read, write, 0x00, usize::MAX).map_err(ForwardError)
+        }
+
+        fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+            transfer_in_place(&mut self.inner, words, usize::MAX).map_err(ForwardError)
+        }
+
+        fn flush(&mut self) -> Result<(), Self::Error> {
+            // TODO: This API doesn't exist in 0.2.7
+            Ok(())
+        }
+    }
+
+    impl<T, E> eh1_0::spi::ErrorType for Forward<T, ForwardSpiU16>
+    where
+        T: Transfer<u16, Error = E>,
         E: core::fmt::Debug,
+    {
+        type Error = ForwardError<E>;
+    }
+
+    impl<T, E> eh1_0::spi::SpiBus<u16> for Forward<T, ForwardSpiU16>
+    where
+        T: Transfer<u16, Error = E> + Write<u16, Error = E>,
+        E: core::fmt::Debug,
+    {
+        fn read(&mut self, words: &mut [u16]) -> Result<(), Self::Error> {
+            // Send empty words
+            read(&mut self.inner, words, 0, usize::MAX).map_err(ForwardError)
+        }
+
+        fn write(&mut self, words: &[u16]) -> Result<(), Self::Error> {
+            write(&mut self.inner, words, usize::MAX).map_err(ForwardError)
+        }
+
+        fn transfer(&mut self, read: &mut [u16], write: &[u16]) -> Result<(), Self::Error> {
+            transfer(&mut self.inner, read, write, 0, usize::MAX).map_err(ForwardError)
+        }
+
+        fn transfer_in_place(&mut self, words: &mut [u16]) -> Result<(), Self::Error> {
+            transfer_in_place(&mut self.inner, words, usize::MAX).map_err(ForwardError)
+        }
+
+        fn flush(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl<T, E> eh1_0::spi::ErrorType for Forward<T, ForwardSpiU32>
+    where
+        T: Transfer<u32, Error = E>,
+        E: core::fmt::Debug,
+    {
+        type Error = ForwardError<E>;
+    }
+
+    impl<T, E> eh1_0::spi::SpiBus<u32> for Forward<T, ForwardSpiU32>
+    where
+        T: Transfer<u32, Error = E> + Write<u32, Error = E>,
+        E: core::fmt::Debug,
+    {
+        fn read(&mut self, words: &mut [u32]) -> Result<(), Self::Error> {
+            // Send empty words
+            read(&mut self.inner, words, 0, usize::MAX).map_err(ForwardError)
+        }
+
+        fn write(&mut self, words: &[u32]) -> Result<(), Self::Error> {
+            write(&mut self.inner, words, usize::MAX).map_err(ForwardError)
+        }
+
+        fn transfer(&mut self, read: &mut [u32], write: &[u32]) -> Result<(), Self::Error> {
+            transfer(&mut self.inner, read, write, 0, usize::MAX).map_err(ForwardError)
+        }
+
+        fn transfer_in_place(&mut self, words: &mut [u32]) -> Result<(), Self::Error> {
+            transfer_in_place(&mut self.inner, words, usize::MAX).map_err(ForwardError)
+        }
+
+        fn flush(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl<B: Debug, P: Debug> eh1_0::spi::Error for SpiDeviceError<B, P> {
+        fn kind(&self) -> eh1_0::spi::ErrorKind {
+            match self {
+                SpiDeviceError::Bus(_) => eh1_0::spi::ErrorKind::Other,
+                SpiDeviceError::ChipSelect(_) => eh1_0::spi::ErrorKind::ChipSelectFault,
+            }
+        }
+    }
+
+    impl<B, CS, D, BE, PE> eh1_0::spi::ErrorType for ForwardSpiDevice<B, CS, D>
+    where
+        B: Transfer<u8, Error = BE>,
+        CS: eh0_2::digital::v2::OutputPin<Error = PE>,
+        BE: Debug,
+        PE: Debug,
+    {
+        type Error = SpiDeviceError<BE, PE>;
+    }
+
+    impl<B, CS, D, BE, PE> eh1_0::spi::SpiDevice<u8> for ForwardSpiDevice<B, CS, D>
+    where
+        B: Transfer<u8, Error = BE> + Write<u8, Error = BE>,
+        CS: eh0_2::digital::v2::OutputPin<Error = PE>,
+        D: eh0_2::blocking::delay::DelayUs<u32>,
+        BE: Debug,
+        PE: Debug,
+    {
+        fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+            self.cs.set_low().map_err(SpiDeviceError::ChipSelect)?;
+
+            let result = operations.iter_mut().try_for_each(|op| match op {
+                Operation::Read(words) => read(&mut self.bus, words, 0x00, usize::MAX),
+                Operation::Write(words) => write(&mut self.bus, words, usize::MAX),
+                Operation::Transfer(read, write) => {
+                    transfer(&mut self.bus, read, write, 0x00, usize::MAX)
+                }
+                Operation::TransferInPlace(words) => {
+                    transfer_in_place(&mut self.bus, words, usize::MAX)
+                }
+                Operation::DelayNs(ns) => {
+                    self.delay.delay_us(ns.div_ceil(1000));
+                    Ok(())
+                }
+            });
+
+            // Always release the chip select, reporting bus errors first
+            let cs_result = self.cs.set_high().map_err(SpiDeviceError::ChipSelect);
+            result.map_err(SpiDeviceError::Bus)?;
+            cs_result
+        }
+    }
+
+    impl<T, E> eh1_0::spi::ErrorType for Forward<FullDuplexBus<T>, ForwardFullDuplex>
+    where
+        T: FullDuplex<u8, Error = E>,
+        E: Debug,
+    {
+        type Error = ForwardError<E>;
+    }
+
+    impl<T, E> FullDuplexBus<T>
+    where
+        T: FullDuplex<u8, Error = E>,
+    {
+        /// Send a word and receive the word clocked in, keeping the FIFOs in lockstep
+        fn exchange(&mut self, word: u8) -> Result<u8, E> {
+            nb::block!(self.bus.send(word))?;
+            nb::block!(self.bus.read())
+        }
+    }
+
+    impl<T, E> eh1_0::spi::SpiBus<u8> for Forward<FullDuplexBus<T>, ForwardFullDuplex>
+    where
+        T: FullDuplex<u8, Error = E>,
+        E: Debug,
     {
         fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
-            // Clear buffer to send empty bytes
             for word in words.iter_mut() {
-                *word = 0x00;
+                // Send empty bytes
+                *word = self.inner.exchange(0x00).map_err(ForwardError)?;
             }
-            self.inner.transfer(words).map_err(ForwardError)?;
             Ok(())
         }
 
         fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
-            self.inner.write(words).map_err(ForwardError)
+            for word in words.iter() {
+                self.inner.exchange(*word).map_err(ForwardError)?;
+            }
+            Ok(())
         }
 
         fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
-            read.copy_from_slice(&write[..read.len()]);
-            self.inner.transfer(read).map_err(ForwardError)?;
+            // Pad writes with empty bytes and discard surplus reads
+            for i in 0..read.len().max(write.len()) {
+                let word = write.get(i).copied().unwrap_or(0x00);
+                let word = self.inner.exchange(word).map_err(ForwardError)?;
+                if let Some(r) = read.get_mut(i) {
+                    *r = word;
+                }
+            }
             Ok(())
         }
 
         fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
-            self.inner.transfer(words).map_err(ForwardError)?;
+            for word in words.iter_mut() {
+                *word = self.inner.exchange(*word).map_err(ForwardError)?;
+            }
             Ok(())
         }
 
         fn flush(&mut self) -> Result<(), Self::Error> {
-            // TODO: This API doesn't exist in 0.2.7
+            // Drain any pending received words
+            loop {
+                match self.inner.bus.read() {
+                    Ok(_) => (),
+                    Err(nb::Error::WouldBlock) => return Ok(()),
+                    Err(nb::Error::Other(e)) => return Err(ForwardError(e)),
+                }
+            }
+        }
+    }
+
+    impl<E: Debug> eh1_0::spi::Error for WriteOnlyError<E> {
+        fn kind(&self) -> eh1_0::spi::ErrorKind {
+            eh1_0::spi::ErrorKind::Other
+        }
+    }
+
+    impl<T, E> eh1_0::spi::ErrorType for Forward<WriteOnlyBus<T>, ForwardWriteOnly>
+    where
+        T: Write<u8, Error = E>,
+        E: Debug,
+    {
+        type Error = WriteOnlyError<E>;
+    }
+
+    impl<T, E> eh1_0::spi::SpiBus<u8> for Forward<WriteOnlyBus<T>, ForwardWriteOnly>
+    where
+        T: Write<u8, Error = E>,
+        E: Debug,
+    {
+        fn read(&mut self, _words: &mut [u8]) -> Result<(), Self::Error> {
+            Err(WriteOnlyError::Unsupported)
+        }
+
+        fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+            self.inner.bus.write(words).map_err(WriteOnlyError::Bus)
+        }
+
+        fn transfer(&mut self, _read: &mut [u8], _write: &[u8]) -> Result<(), Self::Error> {
+            Err(WriteOnlyError::Unsupported)
+        }
+
+        fn transfer_in_place(&mut self, _words: &mut [u8]) -> Result<(), Self::Error> {
+            Err(WriteOnlyError::Unsupported)
+        }
+
+        fn flush(&mut self) -> Result<(), Self::Error> {
             Ok(())
         }
     }
+
+    impl<B, CS, BE, PE, const N: usize> eh1_0::spi::ErrorType for ForwardTransactionalDevice<B, CS, N>
+    where
+        B: eh0_2::blocking::spi::Transactional<u8, Error = BE>,
+        CS: eh0_2::digital::v2::OutputPin<Error = PE>,
+        BE: Debug,
+        PE: Debug,
+    {
+        type Error = SpiDeviceError<TransactionalError<BE>, PE>;
+    }
+
+    impl<B, CS, BE, PE, const N: usize> eh1_0::spi::SpiDevice<u8>
+        for ForwardTransactionalDevice<B, CS, N>
+    where
+        B: eh0_2::blocking::spi::Transactional<u8, Error = BE>,
+        CS: eh0_2::digital::v2::OutputPin<Error = PE>,
+        BE: Debug,
+        PE: Debug,
+    {
+        fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+            use eh0_2::blocking::spi::Operation as Op;
+
+            // Check the transaction fits in a single exec call before touching any buffers
+            let mut count = 0;
+            for op in operations.iter() {
+                count += match op {
+                    Operation::DelayNs(_) => {
+                        return Err(SpiDeviceError::Bus(TransactionalError::Unsupported))
+                    }
+                    Operation::Transfer(read, write) => {
+                        1 + usize::from(write.len() > read.len())
+                            + usize::from(read.len() > write.len())
+                    }
+                    _ => 1,
+                };
+            }
+            if count > N {
+                return Err(SpiDeviceError::Bus(TransactionalError::TooManyOperations));
+            }
+
+            let mut batch: [Op<'_, u8>; N] = core::array::from_fn(|_| Op::Write(&[]));
+            let mut slots = batch.iter_mut();
+            let mut push = |op| {
+                if let Some(slot) = slots.next() {
+                    *slot = op;
+                }
+            };
+            for op in operations.iter_mut() {
+                match op {
+                    Operation::Read(words) => {
+                        // Send empty bytes
+                        words.fill(0x00);
+                        push(Op::Transfer(words));
+                    }
+                    Operation::Write(words) => push(Op::Write(words)),
+                    Operation::Transfer(read, write) => {
+                        let common = read.len().min(write.len());
+                        let (read, read_extra) = read.split_at_mut(common);
+                        let (write, write_extra) = write.split_at(common);
+
+                        // Pad writes with empty bytes and discard surplus reads
+                        read.copy_from_slice(write);
+                        push(Op::Transfer(read));
+                        if !write_extra.is_empty() {
+                            push(Op::Write(write_extra));
+                        }
+                        if !read_extra.is_empty() {
+                            read_extra.fill(0x00);
+                            push(Op::Transfer(read_extra));
+                        }
+                    }
+                    Operation::TransferInPlace(words) => push(Op::Transfer(words)),
+                    // Rejected above
+                    Operation::DelayNs(_) => (),
+                }
+            }
+
+            self.cs.set_low().map_err(SpiDeviceError::ChipSelect)?;
+            let result = self.bus.exec(&mut batch[..count]);
+
+            // Always release the chip select, reporting bus errors first
+            let cs_result = self.cs.set_high().map_err(SpiDeviceError::ChipSelect);
+            result.map_err(|e| SpiDeviceError::Bus(TransactionalError::Bus(e)))?;
+            cs_result
+        }
+    }
+
+    impl<T, E> eh1_0::spi::ErrorType for ForwardSpi<T>
+    where
+        T: Transfer<u8, Error = E>,
+        E: core::fmt::Debug,
+    {
+        type Error = ForwardError<E>;
+    }
+
+    impl<T, E> eh1_0::spi::SpiBus<u8> for ForwardSpi<T>
+    where
+        T: Transfer<u8, Error = E> + Write<u8, Error = E>,
+        E: core::fmt::Debug,
+    {
+        fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+            let max = chunk_len(self.config.max_chunk);
+            read(&mut self.inner, words, self.config.fill, max).map_err(ForwardError)
+        }
+
+        fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+            let max = chunk_len(self.config.max_chunk);
+            write(&mut self.inner, words, max).map_err(ForwardError)
+        }
+
+        fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+            let max = chunk_len(self.config.max_chunk);
+            transfer(&mut self.inner, read, write, self.config.fill, max).map_err(ForwardError)
+        }
+
+        fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+            let max = chunk_len(self.config.max_chunk);
+            transfer_in_place(&mut self.inner, words, max).map_err(ForwardError)
+        }
+
+        fn flush(&mut self) -> Result<(), Self::Error> {
+            match self.config.flush {
+                Some(flush) => flush(&mut self.inner).map_err(ForwardError),
+                None => Ok(()),
+            }
+        }
+    }
+}
+
+/// SPI (async)
+///
+/// These run the blocking `e-h@0.2.x` calls to completion within each future, so the executor
+/// is blocked for the duration of each operation. This allows async drivers to be used with
+/// blocking HALs but provides none of the concurrency benefits of a native async implementation.
+mod spi_async {
+    use super::{Forward, ForwardSpi, ForwardSpiDevice};
+    use core::fmt::Debug;
+    use eh0_2::blocking::spi::{Transfer, Write};
+    use eh1_0::spi::Operation;
+
+    impl<T, E> eh1_0_async::spi::SpiBus<u8> for Forward<T>
+    where
+        T: Transfer<u8, Error = E> + Write<u8, Error = E>,
+        E: Debug,
+    {
+        async fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+            eh1_0::spi::SpiBus::read(self, words)
+        }
+
+        async fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+            eh1_0::spi::SpiBus::write(self, words)
+        }
+
+        async fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+            eh1_0::spi::SpiBus::transfer(self, read, write)
+        }
+
+        async fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+            eh1_0::spi::SpiBus::transfer_in_place(self, words)
+        }
+
+        async fn flush(&mut self) -> Result<(), Self::Error> {
+            eh1_0::spi::SpiBus::flush(self)
+        }
+    }
+
+    impl<T, E> eh1_0_async::spi::SpiBus<u8> for ForwardSpi<T>
+    where
+        T: Transfer<u8, Error = E> + Write<u8, Error = E>,
+        E: Debug,
+    {
+        async fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+            eh1_0::spi::SpiBus::read(self, words)
+        }
+
+        async fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+            eh1_0::spi::SpiBus::write(self, words)
+        }
+
+        async fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+            eh1_0::spi::SpiBus::transfer(self, read, write)
+        }
+
+        async fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+            eh1_0::spi::SpiBus::transfer_in_place(self, words)
+        }
+
+        async fn flush(&mut self) -> Result<(), Self::Error> {
+            eh1_0::spi::SpiBus::flush(self)
+        }
+    }
+
+    impl<B, CS, D, BE, PE> eh1_0_async::spi::SpiDevice<u8> for ForwardSpiDevice<B, CS, D>
+    where
+        B: Transfer<u8, Error = BE> + Write<u8, Error = BE>,
+        CS: eh0_2::digital::v2::OutputPin<Error = PE>,
+        D: eh0_2::blocking::delay::DelayUs<u32>,
+        BE: Debug,
+        PE: Debug,
+    {
+        async fn transaction(
+            &mut self,
+            operations: &mut [Operation<'_, u8>],
+        ) -> Result<(), Self::Error> {
+            eh1_0::spi::SpiDevice::transaction(self, operations)
+        }
+    }
 }
 
 // I2C (blocking)
 mod i2c {
     use super::{Forward, ForwardError};
+    use crate::convert::Convert;
     use eh1_0::i2c::SevenBitAddress;
 
     use eh0_2::blocking::i2c::{self as eh0_2_i2c};
@@ -387,18 +1955,65 @@ mod i2c {
             address: SevenBitAddress,
             operations: &mut [eh1_0::i2c::Operation],
         ) -> Result<(), Self::Error> {
-            let ops = operations.iter_mut().map(|op| match op {
-                eh1_0::i2c::Operation::Read(ref mut buff) => {
-                    eh0_2::blocking::i2c::Operation::Read(buff)
-                }
-                eh1_0::i2c::Operation::Write(buff) => eh0_2::blocking::i2c::Operation::Write(buff),
-            });
+            let ops = operations.iter_mut().map(Convert::convert);
 
             self.inner.exec_iter(address, ops).map_err(ForwardError)
         }
     }
 }
 
+/// I2C (async)
+///
+/// As with SPI, these run the blocking `e-h@0.2.x` calls to completion within each future,
+/// blocking the executor for the duration of each operation.
+mod i2c_async {
+    use super::Forward;
+    use eh0_2::blocking::i2c as eh0_2_i2c;
+    use eh1_0::i2c::SevenBitAddress;
+
+    impl<T, E> eh1_0_async::i2c::I2c<SevenBitAddress> for Forward<T>
+    where
+        T: eh0_2_i2c::Write<Error = E>
+            + eh0_2_i2c::Read<Error = E>
+            + eh0_2_i2c::WriteRead<Error = E>
+            + eh0_2_i2c::TransactionalIter<Error = E>,
+        E: core::fmt::Debug,
+    {
+        async fn read(
+            &mut self,
+            address: SevenBitAddress,
+            words: &mut [u8],
+        ) -> Result<(), Self::Error> {
+            eh1_0::i2c::I2c::read(self, address, words)
+        }
+
+        async fn write(
+            &mut self,
+            address: SevenBitAddress,
+            words: &[u8],
+        ) -> Result<(), Self::Error> {
+            eh1_0::i2c::I2c::write(self, address, words)
+        }
+
+        async fn write_read(
+            &mut self,
+            address: SevenBitAddress,
+            bytes: &[u8],
+            buffer: &mut [u8],
+        ) -> Result<(), Self::Error> {
+            eh1_0::i2c::I2c::write_read(self, address, bytes, buffer)
+        }
+
+        async fn transaction(
+            &mut self,
+            address: SevenBitAddress,
+            operations: &mut [eh1_0::i2c::Operation<'_>],
+        ) -> Result<(), Self::Error> {
+            eh1_0::i2c::I2c::transaction(self, address, operations)
+        }
+    }
+}
+
 /// Serial (UART etc.)
 #[cfg(feature = "embedded-io")]
 #[cfg_attr(docsrs, doc(cfg(feature = "embedded-io")))]
@@ -18,7 +18,15 @@
 //! `v1.0.x` types.
 //!
 //! Note that GPIO pins will require annotation with marker types (see [markers]) to select
-//! input / output / combined modes.
+//! input / output / combined modes, or can be forwarded with the [ForwardPinCompat] helpers
+//! (`.forward_input()`, `.forward_output()` or `.forward_io()`).
+//! Similarly SPI buses with 16 or 32-bit words are selected with the
+//! [ForwardSpiU16](markers::ForwardSpiU16) / [ForwardSpiU32](markers::ForwardSpiU32) markers.
+//!
+//! Forwarded SPI buses with 8-bit words (including [ForwardSpi]), [ForwardSpiDevice] and I2C
+//! peripherals also implement the `embedded-hal-async` traits, however these run the blocking
+//! `v0.2.x` calls within each future so will block the executor until each operation completes.
+//! Wide word, full duplex and write-only SPI buses are blocking only.
 //!
 //!```
 //! # use core::convert::Infallible;
@@ -67,7 +75,8 @@
 //! use with `v0.2.x` consumers, so you can drop these wrapped types into drivers expecting
 //! `v0.2.x` types.
 //!
-//! Note that input and output pins require `.reverse_cell()` as a workaround for mutability changes.
+//! Note that input pins (and other `&self` APIs such as `StatefulOutputPin`) require
+//! `.reverse_cell()` as a workaround for mutability changes, output pins can use `.reverse()`.
 //!
 //!```
 //! # use core::convert::Infallible;
@@ -96,7 +105,7 @@
 //! let _ = eh1_0::digital::OutputPin::set_high(&mut new);
 //!
 //! // Apply backwards compatibility wrapper
-//! let mut old = new.reverse_cell();
+//! let mut old = new.reverse();
 //! // Access via e-h v0.2.x methods
 //! let _ = eh0_2::digital::v2::OutputPin::set_high(&mut old);
 //!```
@@ -147,6 +156,13 @@
 //! Do not enable it if you do not need it.
 //!
 //! Note that this introduces a dependency on the [core allocation library](https://doc.rust-lang.org/alloc/).
+//!
+//! ### `critical-section`
+//! The `critical-section` feature enables `.reverse_cs()`, which wraps `embedded-hal` `1.0.0`
+//! pins in a [critical-section](https://docs.rs/critical-section) mutex rather than a `RefCell`,
+//! so reversed pins are `Sync` and may be shared with interrupt handlers.
+//!
+//! This requires a `critical-section` implementation to be provided for your target.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![no_std]
@@ -162,12 +178,33 @@ pub use eh1_0;
 #[cfg_attr(docsrs, doc(cfg(feature = "embedded-io")))]
 pub use embedded_io;
 
+pub mod convert;
 mod forward;
 pub mod markers;
+#[cfg(target_has_atomic = "8")]
+mod notifier;
 mod reverse;
 
 // Forward compatibility wrapper trait, access using `.forward()`
-pub use forward::{Forward, ForwardCompat};
+pub use forward::{
+    DelayCalibration, FlushFn, Forward, ForwardCalibratedDelay, ForwardCompat, ForwardCountDown,
+    ForwardDelay, ForwardPinCompat, ForwardSpi, ForwardSpiCompat, ForwardSpiConfig,
+    ForwardSpiDevice, ForwardTransactionalDevice, FullDuplexBus, Polled, SpiDeviceError,
+    TransactionalError, WriteOnlyBus, WriteOnlyError, YieldNow,
+};
+
+// Interrupt notifications for forwarded pins, access using `.forward_interrupt()`
+#[cfg(target_has_atomic = "8")]
+pub use forward::Notified;
+#[cfg(target_has_atomic = "8")]
+pub use notifier::{NotifierInUse, PinNotifier};
 
 // Reverse compatibility wrapper trait, access using `.reverse()`
-pub use reverse::{Reverse, ReverseCompat};
+pub use reverse::{
+    CellError, IntoInputFn, IntoOutputFn, Reverse, ReverseCell, ReverseCompat, ReverseIoInput,
+    ReverseIoOutput, ReverseTryCell, V1ErrorPolicy, V1Pin,
+};
+
+#[cfg(feature = "critical-section")]
+#[cfg_attr(docsrs, doc(cfg(feature = "critical-section")))]
+pub use reverse::ReverseCs;
@@ -6,3 +6,46 @@ pub struct ForwardOutputPin;
 
 /// Marker for input-output pins
 pub struct ForwardIoPin;
+
+/// Marker for output pins with a native `ToggleableOutputPin` implementation
+pub struct ForwardToggleableOutputPin;
+
+/// Marker for deprecated `digital::v1` input pins
+pub struct ForwardV1InputPin;
+
+/// Marker for deprecated `digital::v1` output pins
+pub struct ForwardV1OutputPin;
+
+/// Marker for input pins providing async `Wait` by polling
+/// (see [ForwardPinCompat::forward_polled](crate::ForwardPinCompat::forward_polled))
+pub struct ForwardPolledInput;
+
+/// Marker for input pins providing async `Wait` using interrupt notifications
+/// (see [ForwardPinCompat::forward_interrupt](crate::ForwardPinCompat::forward_interrupt))
+pub struct ForwardInterruptInput;
+
+/// Marker for [ForwardDelay](crate::ForwardDelay) delays implementing only `DelayMs<u32>`
+pub struct ForwardDelayMs;
+
+/// Marker for [ForwardDelay](crate::ForwardDelay) delays implementing both `DelayUs<u32>` and `DelayMs<u32>`
+pub struct ForwardDelayUsMs;
+
+/// Marker for [ForwardDelay](crate::ForwardDelay) delays implementing only `DelayUs<u16>`
+pub struct ForwardDelayUs16;
+
+/// Marker for [ForwardDelay](crate::ForwardDelay) delays implementing only `DelayUs<u8>`
+pub struct ForwardDelayUs8;
+
+/// Marker for SPI buses with 16-bit words, implementing `Transfer<u16>` and `Write<u16>`
+pub struct ForwardSpiU16;
+
+/// Marker for SPI buses with 32-bit words, implementing `Transfer<u32>` and `Write<u32>`
+pub struct ForwardSpiU32;
+
+/// Marker for SPI buses implementing only the non-blocking `FullDuplex<u8>` trait
+/// (see [ForwardSpiCompat::forward_full_duplex](crate::ForwardSpiCompat::forward_full_duplex))
+pub struct ForwardFullDuplex;
+
+/// Marker for SPI buses implementing only the blocking `Write<u8>` trait
+/// (see [ForwardSpiCompat::forward_write_only](crate::ForwardSpiCompat::forward_write_only))
+pub struct ForwardWriteOnly;
@@ -0,0 +1,158 @@
+//! Embedded HAL Compat pin notifier
+//! Interrupt-safe wakeups for forwarded async pins
+// Copyright 2021 Ryan Kurte
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use core::task::Waker;
+
+/// No waker registration or wakeup in progress
+const WAITING: u8 = 0;
+/// A waker is being registered
+const REGISTERING: u8 = 0b01;
+/// A wakeup is in progress
+const WAKING: u8 = 0b10;
+
+/// Interrupt-safe notifier used to wake futures waiting on forwarded pins,
+/// see [ForwardPinCompat::forward_interrupt](crate::ForwardPinCompat::forward_interrupt).
+///
+/// This is intended to live in a `static` and be signalled by calling [PinNotifier::notify]
+/// from the interrupt handler (ie. EXTI) associated with the pin.
+///
+/// Each notifier wakes a single pin, and may only be claimed by one forwarded pin at a time.
+/// Where pins share an interrupt line, use a notifier per pin and notify each of them from
+/// the shared handler.
+///
+/// ```
+/// use embedded_hal_compat::PinNotifier;
+///
+/// static BUTTON: PinNotifier = PinNotifier::new();
+///
+/// // Call from the pin interrupt handler
+/// fn on_exti() {
+///     BUTTON.notify();
+/// }
+/// # on_exti();
+/// ```
+pub struct PinNotifier {
+    claimed: AtomicBool,
+    state: AtomicU8,
+    waker: UnsafeCell<Option<Waker>>,
+}
+
+// Access to the waker is arbitrated by `state`
+unsafe impl Send for PinNotifier {}
+unsafe impl Sync for PinNotifier {}
+
+impl PinNotifier {
+    /// Create a new pin notifier
+    pub const fn new() -> Self {
+        Self {
+            claimed: AtomicBool::new(false),
+            state: AtomicU8::new(WAITING),
+            waker: UnsafeCell::new(None),
+        }
+    }
+
+    /// Wake the future waiting on the associated pin (if any),
+    /// call this from the pin interrupt handler
+    pub fn notify(&self) {
+        if let Some(waker) = self.take() {
+            waker.wake();
+        }
+    }
+
+    /// Claim the notifier for a single pin, returning `None` if it is already claimed
+    pub(crate) fn claim(&self) -> Option<Claim<'_>> {
+        self.claimed
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .ok()
+            .map(|_| Claim(self))
+    }
+
+    /// Register the waker to be woken on the next notification
+    fn register(&self, waker: &Waker) {
+        match self.state.compare_exchange(
+            WAITING,
+            REGISTERING,
+            Ordering::Acquire,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => {
+                // Safety: the REGISTERING state grants exclusive access to the waker
+                unsafe {
+                    let slot = &mut *self.waker.get();
+                    match slot {
+                        Some(w) if w.will_wake(waker) => (),
+                        _ => *slot = Some(waker.clone()),
+                    }
+                }
+
+                if self
+                    .state
+                    .compare_exchange(REGISTERING, WAITING, Ordering::AcqRel, Ordering::Acquire)
+                    .is_err()
+                {
+                    // A notification arrived during registration, so wake immediately
+                    // Safety: notify() does not touch the waker while REGISTERING is set
+                    let waker = unsafe { (*self.waker.get()).take() };
+                    self.state.swap(WAITING, Ordering::AcqRel);
+                    if let Some(waker) = waker {
+                        waker.wake();
+                    }
+                }
+            }
+            // A notification (or another registration) is in progress,
+            // so wake immediately to register again
+            Err(_) => waker.wake_by_ref(),
+        }
+    }
+
+    /// Take the registered waker if no registration is in progress
+    fn take(&self) -> Option<Waker> {
+        match self.state.fetch_or(WAKING, Ordering::AcqRel) {
+            WAITING => {
+                // Safety: the WAKING state grants exclusive access to the waker
+                let waker = unsafe { (*self.waker.get()).take() };
+                self.state.fetch_and(!WAKING, Ordering::Release);
+                waker
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for PinNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Exclusive claim on a [PinNotifier], released on drop
+pub(crate) struct Claim<'a>(&'a PinNotifier);
+
+impl Claim<'_> {
+    /// Register the waker to be woken on the next notification
+    pub(crate) fn register(&self, waker: &Waker) {
+        self.0.register(waker)
+    }
+}
+
+impl Drop for Claim<'_> {
+    fn drop(&mut self) {
+        self.0.claimed.store(false, Ordering::Release);
+    }
+}
+
+/// Error returned when forwarding a pin with a [PinNotifier] already claimed by another pin,
+/// see [ForwardPinCompat::forward_interrupt](crate::ForwardPinCompat::forward_interrupt).
+///
+/// This contains the pin so it may be recovered.
+pub struct NotifierInUse<T>(pub T);
+
+impl<T> fmt::Debug for NotifierInUse<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NotifierInUse")
+    }
+}
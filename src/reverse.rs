@@ -2,8 +2,9 @@
 //! A compatibility layer to alleviate (some) of the issues resolving from changes to embedded-hal
 // Copyright 2021 Ryan Kurte
 
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
 use core::fmt::Debug;
+use core::ops::Deref;
 
 /// Reverse compatibility container object.
 /// This is generic over different E-H types and will provide adaption
@@ -19,8 +20,24 @@ pub trait ReverseCompat<T> {
     fn reverse(self) -> Reverse<T>;
 
     /// Create an e-h-c reverse compatibility wrapper using a ref-cell for interior mutability
-    /// (required for InputPin types)
-    fn reverse_cell(self) -> Reverse<RefCell<T>>;
+    /// (required for `&self` APIs such as InputPin and StatefulOutputPin types)
+    fn reverse_cell(self) -> Reverse<ReverseCell<T>>;
+
+    /// Create an e-h-c reverse compatibility wrapper using a ref-cell for interior mutability,
+    /// returning [CellError::BorrowConflict] on conflicting borrows rather than panicking
+    fn reverse_try_cell(self) -> Reverse<ReverseTryCell<T>>;
+
+    /// Create an e-h-c reverse compatibility wrapper exposing deprecated `digital::v1` pins,
+    /// with errors handled according to the provided [V1ErrorPolicy]
+    fn reverse_v1(self, policy: V1ErrorPolicy) -> Reverse<V1Pin<T>>
+    where
+        T: eh1_0::digital::ErrorType;
+
+    /// Create an e-h-c reverse compatibility wrapper using a critical-section mutex for
+    /// interior mutability, so the wrapper is `Sync` and may be shared with interrupt handlers
+    #[cfg(feature = "critical-section")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "critical-section")))]
+    fn reverse_cs(self) -> Reverse<ReverseCs<T>>;
 }
 
 impl<T> ReverseCompat<T> for T {
@@ -31,15 +48,38 @@ impl<T> ReverseCompat<T> for T {
     }
 
     /// Create an e-h-c reverse compatibility wrapper using a ref-cell for interior mutability
-    /// (required for InputPin types)
-    fn reverse_cell(self) -> Reverse<RefCell<T>> {
-        Reverse::new(RefCell::new(self))
+    /// (required for `&self` APIs such as InputPin and StatefulOutputPin types)
+    fn reverse_cell(self) -> Reverse<ReverseCell<T>> {
+        Reverse::new(ReverseCell::new(self))
+    }
+
+    /// Create an e-h-c reverse compatibility wrapper using a ref-cell for interior mutability,
+    /// returning [CellError::BorrowConflict] on conflicting borrows rather than panicking
+    fn reverse_try_cell(self) -> Reverse<ReverseTryCell<T>> {
+        Reverse::new(ReverseTryCell::new(self))
+    }
+
+    /// Create an e-h-c reverse compatibility wrapper exposing deprecated `digital::v1` pins,
+    /// with errors handled according to the provided [V1ErrorPolicy]
+    fn reverse_v1(self, policy: V1ErrorPolicy) -> Reverse<V1Pin<T>>
+    where
+        T: eh1_0::digital::ErrorType,
+    {
+        Reverse::new(V1Pin::new(self, policy))
+    }
+
+    /// Create an e-h-c reverse compatibility wrapper using a critical-section mutex for
+    /// interior mutability, so the wrapper is `Sync` and may be shared with interrupt handlers
+    #[cfg(feature = "critical-section")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "critical-section")))]
+    fn reverse_cs(self) -> Reverse<ReverseCs<T>> {
+        Reverse::new(ReverseCs::new(self))
     }
 }
 
 impl<T> Reverse<T> {
     /// Create a new compatibility wrapper object
-    pub fn new(inner: T) -> Reverse<T> {
+    pub const fn new(inner: T) -> Reverse<T> {
         Reverse { inner }
     }
 
@@ -59,15 +99,356 @@ impl<T> Reverse<T> {
     }
 }
 
+/// Interior mutability wrapper for reversed pins, see [ReverseCompat::reverse_cell]
+///
+/// This dereferences to the underlying [RefCell] for access to the wrapped object.
+pub struct ReverseCell<T>(RefCell<T>);
+
+impl<T> ReverseCell<T> {
+    /// Create a new interior mutability wrapper
+    pub fn new(inner: T) -> Self {
+        Self(RefCell::new(inner))
+    }
+
+    /// Destroy the wrapper, returning the wrapped object
+    pub fn into_inner(self) -> T {
+        self.0.into_inner()
+    }
+}
+
+impl<T> Deref for ReverseCell<T> {
+    type Target = RefCell<T>;
+
+    fn deref(&self) -> &RefCell<T> {
+        &self.0
+    }
+}
+
+/// Non-panicking interior mutability wrapper for reversed pins,
+/// see [ReverseCompat::reverse_try_cell]
+///
+/// This dereferences to the underlying [RefCell] for access to the wrapped object.
+pub struct ReverseTryCell<T>(RefCell<T>);
+
+impl<T> ReverseTryCell<T> {
+    /// Create a new non-panicking interior mutability wrapper
+    pub fn new(inner: T) -> Self {
+        Self(RefCell::new(inner))
+    }
+
+    /// Destroy the wrapper, returning the wrapped object
+    pub fn into_inner(self) -> T {
+        self.0.into_inner()
+    }
+
+    /// Execute the provided closure with mutable access to the wrapped object,
+    /// returning [CellError::BorrowConflict] if the object is already borrowed
+    fn try_with<R, E>(&self, f: impl FnOnce(&mut T) -> Result<R, E>) -> Result<R, CellError<E>> {
+        let mut inner = self
+            .0
+            .try_borrow_mut()
+            .map_err(|_| CellError::BorrowConflict)?;
+        f(&mut inner).map_err(CellError::Pin)
+    }
+}
+
+impl<T> Deref for ReverseTryCell<T> {
+    type Target = RefCell<T>;
+
+    fn deref(&self) -> &RefCell<T> {
+        &self.0
+    }
+}
+
+/// Error type for [ReverseTryCell] wrapped pins
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum CellError<E> {
+    /// Error from the wrapped pin
+    Pin(E),
+    /// The wrapped pin is already borrowed (ie. by a re-entrant call)
+    BorrowConflict,
+}
+
+/// Critical-section based interior mutability wrapper for reversed pins,
+/// see [ReverseCompat::reverse_cs]
+///
+/// Unlike [ReverseCell] this is `Sync`, so wrapped pins may be placed in a `static` and used
+/// from interrupt handlers. Each access runs within a critical section, which prevents an
+/// interrupt pre-empting an access in progress, however nested access from within
+/// [ReverseCs::lock] still panics on the inner `RefCell` borrow. Shared references
+/// (`&Reverse<ReverseCs<T>>`) also implement `OutputPin`, for drivers taking pins by value.
+#[cfg(feature = "critical-section")]
+#[cfg_attr(docsrs, doc(cfg(feature = "critical-section")))]
+pub struct ReverseCs<T>(critical_section::Mutex<RefCell<T>>);
+
+#[cfg(feature = "critical-section")]
+impl<T> ReverseCs<T> {
+    /// Create a new critical-section based interior mutability wrapper
+    pub const fn new(inner: T) -> Self {
+        Self(critical_section::Mutex::new(RefCell::new(inner)))
+    }
+
+    /// Destroy the wrapper, returning the wrapped object
+    pub fn into_inner(self) -> T {
+        self.0.into_inner().into_inner()
+    }
+
+    /// Execute the provided closure with access to the wrapped object within a critical section
+    pub fn lock<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        critical_section::with(|cs| f(&mut self.0.borrow_ref_mut(cs)))
+    }
+}
+
+/// Error handling policy for `digital::v1` pins, which are unable to return errors
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum V1ErrorPolicy {
+    /// Panic on error
+    Panic,
+    /// Silently discard errors
+    Ignore,
+    /// Store the last error, to be read back with [Reverse::take_error]
+    Latch,
+}
+
+/// Wrapper for exposing `e-h@1.0.x` pins via the deprecated `digital::v1` traits,
+/// see [ReverseCompat::reverse_v1]
+pub struct V1Pin<T: eh1_0::digital::ErrorType> {
+    pin: RefCell<T>,
+    policy: V1ErrorPolicy,
+    error: Cell<Option<T::Error>>,
+}
+
+impl<T: eh1_0::digital::ErrorType> V1Pin<T> {
+    /// Create a new `digital::v1` pin wrapper with the provided error policy
+    pub fn new(pin: T, policy: V1ErrorPolicy) -> Self {
+        Self {
+            pin: RefCell::new(pin),
+            policy,
+            error: Cell::new(None),
+        }
+    }
+
+    /// Destroy the wrapper, returning the wrapped pin
+    pub fn into_inner(self) -> T {
+        self.pin.into_inner()
+    }
+
+    /// Apply the error policy to the result of a pin operation
+    fn handle<R: Default>(&self, res: Result<R, T::Error>) -> R {
+        match res {
+            Ok(v) => v,
+            Err(e) => {
+                match self.policy {
+                    V1ErrorPolicy::Panic => panic!("digital::v1 pin error: {:?}", e),
+                    V1ErrorPolicy::Ignore => (),
+                    V1ErrorPolicy::Latch => self.error.set(Some(e)),
+                }
+                R::default()
+            }
+        }
+    }
+}
+
+impl<T: eh1_0::digital::ErrorType> Reverse<V1Pin<T>> {
+    /// Take the last error latched when using [V1ErrorPolicy::Latch]
+    pub fn take_error(&self) -> Option<T::Error> {
+        self.inner.error.take()
+    }
+}
+
+/// Constructor converting an `e-h@1.0.x` output pin into an input pin
+pub type IntoInputFn<I, O, E> = fn(O) -> Result<I, E>;
+
+/// Constructor converting an `e-h@1.0.x` input pin into an output pin with an initial state
+pub type IntoOutputFn<I, O, E> = fn(I, eh1_0::digital::PinState) -> Result<O, E>;
+
+/// Input mode of a reversed `e-h@0.2.x` `IoPin`, built from `e-h@1.0.x` pin constructors
+pub struct ReverseIoInput<I, O, E> {
+    pin: RefCell<I>,
+    into_input: IntoInputFn<I, O, E>,
+    into_output: IntoOutputFn<I, O, E>,
+}
+
+impl<I, O, E> ReverseIoInput<I, O, E> {
+    /// Create a new reversed `IoPin` in input mode
+    pub fn new(
+        pin: I,
+        into_input: IntoInputFn<I, O, E>,
+        into_output: IntoOutputFn<I, O, E>,
+    ) -> Self {
+        Self {
+            pin: RefCell::new(pin),
+            into_input,
+            into_output,
+        }
+    }
+
+    /// Destroy the wrapper, returning the wrapped pin
+    pub fn into_inner(self) -> I {
+        self.pin.into_inner()
+    }
+}
+
+/// Output mode of a reversed `e-h@0.2.x` `IoPin`, built from `e-h@1.0.x` pin constructors
+pub struct ReverseIoOutput<I, O, E> {
+    pin: O,
+    into_input: IntoInputFn<I, O, E>,
+    into_output: IntoOutputFn<I, O, E>,
+}
+
+impl<I, O, E> ReverseIoOutput<I, O, E> {
+    /// Create a new reversed `IoPin` in output mode
+    pub fn new(
+        pin: O,
+        into_input: IntoInputFn<I, O, E>,
+        into_output: IntoOutputFn<I, O, E>,
+    ) -> Self {
+        Self {
+            pin,
+            into_input,
+            into_output,
+        }
+    }
+
+    /// Destroy the wrapper, returning the wrapped pin
+    pub fn into_inner(self) -> O {
+        self.pin
+    }
+}
+
 // note that implementations over Reverse cannot be generic over word type
 // etc. due to orphan rules (ie. what happens if someone else defines a word type?)
 
 // Digital / GPIOs
 mod digital {
-    use super::{Debug, Reverse};
-    use core::cell::RefCell;
+    use super::{
+        CellError, Debug, Reverse, ReverseCell, ReverseIoInput, ReverseIoOutput, ReverseTryCell,
+        V1Pin,
+    };
+    use crate::convert::Convert;
+
+    impl<T: eh1_0::digital::ErrorType> eh1_0::digital::ErrorType for ReverseCell<T> {
+        type Error = T::Error;
+    }
+
+    impl<T: eh1_0::digital::InputPin> eh1_0::digital::InputPin for ReverseCell<T> {
+        fn is_high(&mut self) -> Result<bool, Self::Error> {
+            self.0.get_mut().is_high()
+        }
 
-    impl<T, E> eh0_2::digital::v2::InputPin for Reverse<RefCell<T>>
+        fn is_low(&mut self) -> Result<bool, Self::Error> {
+            self.0.get_mut().is_low()
+        }
+    }
+
+    impl<T: eh1_0::digital::OutputPin> eh1_0::digital::OutputPin for ReverseCell<T> {
+        fn set_high(&mut self) -> Result<(), Self::Error> {
+            self.0.get_mut().set_high()
+        }
+
+        fn set_low(&mut self) -> Result<(), Self::Error> {
+            self.0.get_mut().set_low()
+        }
+    }
+
+    impl<T: eh1_0::digital::StatefulOutputPin> eh1_0::digital::StatefulOutputPin for ReverseCell<T> {
+        fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+            self.0.get_mut().is_set_high()
+        }
+
+        fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+            self.0.get_mut().is_set_low()
+        }
+
+        fn toggle(&mut self) -> Result<(), Self::Error> {
+            self.0.get_mut().toggle()
+        }
+    }
+
+    impl<E: eh1_0::digital::Error> eh1_0::digital::Error for CellError<E> {
+        fn kind(&self) -> eh1_0::digital::ErrorKind {
+            match self {
+                CellError::Pin(e) => e.kind(),
+                CellError::BorrowConflict => eh1_0::digital::ErrorKind::Other,
+            }
+        }
+    }
+
+    impl<T: eh1_0::digital::ErrorType> eh1_0::digital::ErrorType for ReverseTryCell<T> {
+        type Error = CellError<T::Error>;
+    }
+
+    impl<T: eh1_0::digital::InputPin> eh1_0::digital::InputPin for ReverseTryCell<T> {
+        fn is_high(&mut self) -> Result<bool, Self::Error> {
+            self.0.get_mut().is_high().map_err(CellError::Pin)
+        }
+
+        fn is_low(&mut self) -> Result<bool, Self::Error> {
+            self.0.get_mut().is_low().map_err(CellError::Pin)
+        }
+    }
+
+    impl<T: eh1_0::digital::OutputPin> eh1_0::digital::OutputPin for ReverseTryCell<T> {
+        fn set_high(&mut self) -> Result<(), Self::Error> {
+            self.0.get_mut().set_high().map_err(CellError::Pin)
+        }
+
+        fn set_low(&mut self) -> Result<(), Self::Error> {
+            self.0.get_mut().set_low().map_err(CellError::Pin)
+        }
+    }
+
+    impl<T: eh1_0::digital::StatefulOutputPin> eh1_0::digital::StatefulOutputPin for ReverseTryCell<T> {
+        fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+            self.0.get_mut().is_set_high().map_err(CellError::Pin)
+        }
+
+        fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+            self.0.get_mut().is_set_low().map_err(CellError::Pin)
+        }
+
+        fn toggle(&mut self) -> Result<(), Self::Error> {
+            self.0.get_mut().toggle().map_err(CellError::Pin)
+        }
+    }
+
+    impl<T, E> eh0_2::digital::v2::InputPin for Reverse<ReverseTryCell<T>>
+    where
+        T: eh1_0::digital::InputPin<Error = E>,
+        E: Debug,
+    {
+        type Error = CellError<E>;
+
+        /// Is the input pin high?
+        fn is_high(&self) -> Result<bool, Self::Error> {
+            self.inner.try_with(|pin| pin.is_high())
+        }
+
+        /// Is the input pin low?
+        fn is_low(&self) -> Result<bool, Self::Error> {
+            self.inner.try_with(|pin| pin.is_low())
+        }
+    }
+
+    impl<T, E> eh0_2::digital::v2::StatefulOutputPin for Reverse<ReverseTryCell<T>>
+    where
+        T: eh1_0::digital::StatefulOutputPin<Error = E>,
+        E: Debug,
+    {
+        /// Is the pin in drive high mode?
+        fn is_set_high(&self) -> Result<bool, Self::Error> {
+            self.inner.try_with(|pin| pin.is_set_high())
+        }
+
+        /// Is the pin in drive low mode?
+        fn is_set_low(&self) -> Result<bool, Self::Error> {
+            self.inner.try_with(|pin| pin.is_set_low())
+        }
+    }
+
+    impl<T, E> eh0_2::digital::v2::InputPin for Reverse<ReverseCell<T>>
     where
         T: eh1_0::digital::InputPin<Error = E>,
         E: Debug,
@@ -85,7 +466,7 @@ mod digital {
         }
     }
 
-    impl<T, E> eh0_2::digital::v2::OutputPin for Reverse<RefCell<T>>
+    impl<T, E> eh0_2::digital::v2::OutputPin for Reverse<T>
     where
         T: eh1_0::digital::OutputPin<Error = E>,
         E: Debug,
@@ -94,12 +475,255 @@ mod digital {
 
         /// Set the output as high
         fn set_high(&mut self) -> Result<(), Self::Error> {
-            self.inner.borrow_mut().set_high()
+            self.inner.set_high()
         }
 
         /// Set the output as low
         fn set_low(&mut self) -> Result<(), Self::Error> {
-            self.inner.borrow_mut().set_low()
+            self.inner.set_low()
+        }
+    }
+
+    impl<T, E> eh0_2::digital::v2::StatefulOutputPin for Reverse<ReverseCell<T>>
+    where
+        T: eh1_0::digital::StatefulOutputPin<Error = E>,
+        E: Debug,
+    {
+        /// Is the pin in drive high mode?
+        fn is_set_high(&self) -> Result<bool, Self::Error> {
+            self.inner.borrow_mut().is_set_high()
+        }
+
+        /// Is the pin in drive low mode?
+        fn is_set_low(&self) -> Result<bool, Self::Error> {
+            self.inner.borrow_mut().is_set_low()
+        }
+    }
+
+    impl<T, E> eh0_2::digital::v2::ToggleableOutputPin for Reverse<T>
+    where
+        T: eh1_0::digital::StatefulOutputPin<Error = E>,
+        E: Debug,
+    {
+        type Error = E;
+
+        /// Toggle pin output
+        fn toggle(&mut self) -> Result<(), Self::Error> {
+            self.inner.toggle()
+        }
+    }
+
+    #[cfg(feature = "critical-section")]
+    mod cs {
+        use super::super::{Debug, Reverse, ReverseCs};
+
+        impl<T: eh1_0::digital::ErrorType> eh1_0::digital::ErrorType for ReverseCs<T> {
+            type Error = T::Error;
+        }
+
+        impl<T: eh1_0::digital::InputPin> eh1_0::digital::InputPin for ReverseCs<T> {
+            fn is_high(&mut self) -> Result<bool, Self::Error> {
+                self.0.get_mut().get_mut().is_high()
+            }
+
+            fn is_low(&mut self) -> Result<bool, Self::Error> {
+                self.0.get_mut().get_mut().is_low()
+            }
+        }
+
+        impl<T: eh1_0::digital::OutputPin> eh1_0::digital::OutputPin for ReverseCs<T> {
+            fn set_high(&mut self) -> Result<(), Self::Error> {
+                self.0.get_mut().get_mut().set_high()
+            }
+
+            fn set_low(&mut self) -> Result<(), Self::Error> {
+                self.0.get_mut().get_mut().set_low()
+            }
+        }
+
+        impl<T: eh1_0::digital::StatefulOutputPin> eh1_0::digital::StatefulOutputPin for ReverseCs<T> {
+            fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+                self.0.get_mut().get_mut().is_set_high()
+            }
+
+            fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+                self.0.get_mut().get_mut().is_set_low()
+            }
+
+            fn toggle(&mut self) -> Result<(), Self::Error> {
+                self.0.get_mut().get_mut().toggle()
+            }
+        }
+
+        impl<T, E> eh0_2::digital::v2::InputPin for Reverse<ReverseCs<T>>
+        where
+            T: eh1_0::digital::InputPin<Error = E>,
+            E: Debug,
+        {
+            type Error = E;
+
+            /// Is the input pin high?
+            fn is_high(&self) -> Result<bool, Self::Error> {
+                self.inner.lock(|pin| pin.is_high())
+            }
+
+            /// Is the input pin low?
+            fn is_low(&self) -> Result<bool, Self::Error> {
+                self.inner.lock(|pin| pin.is_low())
+            }
+        }
+
+        impl<T, E> eh0_2::digital::v2::StatefulOutputPin for Reverse<ReverseCs<T>>
+        where
+            T: eh1_0::digital::StatefulOutputPin<Error = E>,
+            E: Debug,
+        {
+            /// Is the pin in drive high mode?
+            fn is_set_high(&self) -> Result<bool, Self::Error> {
+                self.inner.lock(|pin| pin.is_set_high())
+            }
+
+            /// Is the pin in drive low mode?
+            fn is_set_low(&self) -> Result<bool, Self::Error> {
+                self.inner.lock(|pin| pin.is_set_low())
+            }
+        }
+
+        impl<T, E> eh0_2::digital::v2::OutputPin for &Reverse<ReverseCs<T>>
+        where
+            T: eh1_0::digital::OutputPin<Error = E>,
+            E: Debug,
+        {
+            type Error = E;
+
+            /// Set the output as high
+            fn set_high(&mut self) -> Result<(), Self::Error> {
+                self.inner.lock(|pin| pin.set_high())
+            }
+
+            /// Set the output as low
+            fn set_low(&mut self) -> Result<(), Self::Error> {
+                self.inner.lock(|pin| pin.set_low())
+            }
+        }
+    }
+
+    #[allow(deprecated)]
+    impl<T> eh0_2::digital::v1::InputPin for Reverse<V1Pin<T>>
+    where
+        T: eh1_0::digital::InputPin,
+    {
+        /// Is the input pin high?
+        fn is_high(&self) -> bool {
+            let res = self.inner.pin.borrow_mut().is_high();
+            self.inner.handle(res)
+        }
+
+        /// Is the input pin low?
+        fn is_low(&self) -> bool {
+            let res = self.inner.pin.borrow_mut().is_low();
+            self.inner.handle(res)
+        }
+    }
+
+    #[allow(deprecated)]
+    impl<T> eh0_2::digital::v1::OutputPin for Reverse<V1Pin<T>>
+    where
+        T: eh1_0::digital::OutputPin,
+    {
+        /// Set the output as high
+        fn set_high(&mut self) {
+            let res = self.inner.pin.get_mut().set_high();
+            self.inner.handle(res)
+        }
+
+        /// Set the output as low
+        fn set_low(&mut self) {
+            let res = self.inner.pin.get_mut().set_low();
+            self.inner.handle(res)
+        }
+    }
+
+    impl<I, O, E> eh0_2::digital::v2::InputPin for ReverseIoInput<I, O, E>
+    where
+        I: eh1_0::digital::InputPin,
+    {
+        type Error = I::Error;
+
+        /// Is the input pin high?
+        fn is_high(&self) -> Result<bool, Self::Error> {
+            self.pin.borrow_mut().is_high()
+        }
+
+        /// Is the input pin low?
+        fn is_low(&self) -> Result<bool, Self::Error> {
+            self.pin.borrow_mut().is_low()
+        }
+    }
+
+    impl<I, O, E> eh0_2::digital::v2::OutputPin for ReverseIoOutput<I, O, E>
+    where
+        O: eh1_0::digital::OutputPin,
+    {
+        type Error = O::Error;
+
+        /// Set the output as high
+        fn set_high(&mut self) -> Result<(), Self::Error> {
+            self.pin.set_high()
+        }
+
+        /// Set the output as low
+        fn set_low(&mut self) -> Result<(), Self::Error> {
+            self.pin.set_low()
+        }
+    }
+
+    impl<I, O, E> eh0_2::digital::v2::IoPin<ReverseIoInput<I, O, E>, ReverseIoOutput<I, O, E>>
+        for ReverseIoInput<I, O, E>
+    where
+        I: eh1_0::digital::InputPin,
+        O: eh1_0::digital::OutputPin,
+        E: From<O::Error>,
+    {
+        type Error = E;
+
+        /// Already in input mode
+        fn into_input_pin(self) -> Result<ReverseIoInput<I, O, E>, Self::Error> {
+            Ok(self)
+        }
+
+        /// Convert to output mode using the provided constructor
+        fn into_output_pin(
+            self,
+            state: eh0_2::digital::v2::PinState,
+        ) -> Result<ReverseIoOutput<I, O, E>, Self::Error> {
+            let pin = (self.into_output)(self.pin.into_inner(), state.convert())?;
+            Ok(ReverseIoOutput::new(pin, self.into_input, self.into_output))
+        }
+    }
+
+    impl<I, O, E> eh0_2::digital::v2::IoPin<ReverseIoInput<I, O, E>, ReverseIoOutput<I, O, E>>
+        for ReverseIoOutput<I, O, E>
+    where
+        I: eh1_0::digital::InputPin,
+        O: eh1_0::digital::OutputPin,
+        E: From<O::Error>,
+    {
+        type Error = E;
+
+        /// Convert to input mode using the provided constructor
+        fn into_input_pin(self) -> Result<ReverseIoInput<I, O, E>, Self::Error> {
+            let pin = (self.into_input)(self.pin)?;
+            Ok(ReverseIoInput::new(pin, self.into_input, self.into_output))
+        }
+
+        /// Already in output mode, update the output state
+        fn into_output_pin(
+            mut self,
+            state: eh0_2::digital::v2::PinState,
+        ) -> Result<ReverseIoOutput<I, O, E>, Self::Error> {
+            self.pin.set_state(state.convert())?;
+            Ok(self)
         }
     }
 }
@@ -108,12 +732,26 @@ mod digital {
 mod delay {
     use super::Reverse;
 
+    impl<T> eh0_2::blocking::delay::DelayMs<u64> for Reverse<T>
+    where
+        T: eh1_0::delay::DelayNs,
+    {
+        fn delay_ms(&mut self, mut ms: u64) {
+            // Split into chunks that fit in the e-h@1.0.x u32 argument
+            while ms > 0 {
+                let chunk = ms.min(u32::MAX as u64);
+                self.inner.delay_ms(chunk as u32);
+                ms -= chunk;
+            }
+        }
+    }
+
     impl<T> eh0_2::blocking::delay::DelayMs<u32> for Reverse<T>
     where
         T: eh1_0::delay::DelayNs,
     {
         fn delay_ms(&mut self, ms: u32) {
-            self.inner.delay_us(ms * 1000)
+            self.inner.delay_ms(ms)
         }
     }
 
@@ -122,7 +760,30 @@ mod delay {
         T: eh1_0::delay::DelayNs,
     {
         fn delay_ms(&mut self, ms: u16) {
-            self.inner.delay_us(ms as u32 * 1000)
+            self.inner.delay_ms(ms as u32)
+        }
+    }
+
+    impl<T> eh0_2::blocking::delay::DelayMs<u8> for Reverse<T>
+    where
+        T: eh1_0::delay::DelayNs,
+    {
+        fn delay_ms(&mut self, ms: u8) {
+            self.inner.delay_ms(ms as u32)
+        }
+    }
+
+    impl<T> eh0_2::blocking::delay::DelayUs<u64> for Reverse<T>
+    where
+        T: eh1_0::delay::DelayNs,
+    {
+        fn delay_us(&mut self, mut us: u64) {
+            // Split into chunks that fit in the e-h@1.0.x u32 argument
+            while us > 0 {
+                let chunk = us.min(u32::MAX as u64);
+                self.inner.delay_us(chunk as u32);
+                us -= chunk;
+            }
         }
     }
 
@@ -143,6 +804,15 @@ mod delay {
             self.inner.delay_us(us as u32)
         }
     }
+
+    impl<T> eh0_2::blocking::delay::DelayUs<u8> for Reverse<T>
+    where
+        T: eh1_0::delay::DelayNs,
+    {
+        fn delay_us(&mut self, us: u8) {
+            self.inner.delay_us(us as u32)
+        }
+    }
 }
 
 /// SPI (blocking)
@@ -174,6 +844,56 @@ mod spi {
         }
     }
 
+    impl<T, E> eh0_2::blocking::spi::Write<u16> for Reverse<T>
+    where
+        T: eh1_0::spi::SpiBus<u16, Error = E>,
+        E: Debug,
+    {
+        type Error = E;
+
+        fn write(&mut self, words: &[u16]) -> Result<(), Self::Error> {
+            self.inner.write(words)
+        }
+    }
+
+    impl<T, E> eh0_2::blocking::spi::Transfer<u16> for Reverse<T>
+    where
+        T: eh1_0::spi::SpiBus<u16, Error = E>,
+        E: Debug,
+    {
+        type Error = E;
+
+        fn transfer<'a>(&mut self, words: &'a mut [u16]) -> Result<&'a [u16], Self::Error> {
+            self.inner.transfer_in_place(words)?;
+            Ok(words)
+        }
+    }
+
+    impl<T, E> eh0_2::blocking::spi::Write<u32> for Reverse<T>
+    where
+        T: eh1_0::spi::SpiBus<u32, Error = E>,
+        E: Debug,
+    {
+        type Error = E;
+
+        fn write(&mut self, words: &[u32]) -> Result<(), Self::Error> {
+            self.inner.write(words)
+        }
+    }
+
+    impl<T, E> eh0_2::blocking::spi::Transfer<u32> for Reverse<T>
+    where
+        T: eh1_0::spi::SpiBus<u32, Error = E>,
+        E: Debug,
+    {
+        type Error = E;
+
+        fn transfer<'a>(&mut self, words: &'a mut [u32]) -> Result<&'a [u32], Self::Error> {
+            self.inner.transfer_in_place(words)?;
+            Ok(words)
+        }
+    }
+
     impl<T, E> eh0_2::blocking::spi::WriteIter<u8> for Reverse<T>
     where
         T: eh1_0::spi::SpiBus<u8, Error = E>,
@@ -258,6 +978,8 @@ mod spi {
 // I2C (blocking)
 mod i2c {
     use super::{Debug, Reverse};
+    #[cfg(feature = "alloc")]
+    use crate::convert::Convert;
     use eh1_0::i2c::SevenBitAddress;
 
     impl<T, E> eh0_2::blocking::i2c::Read for Reverse<T>
@@ -318,17 +1040,8 @@ mod i2c {
             address: u8,
             operations: &mut [eh0_2::blocking::i2c::Operation],
         ) -> Result<(), Self::Error> {
-            let mut ops: alloc::vec::Vec<eh1_0::i2c::Operation> = operations
-                .iter_mut()
-                .map(|op| match op {
-                    eh0_2::blocking::i2c::Operation::Read(ref mut buff) => {
-                        eh1_0::i2c::Operation::Read(buff)
-                    }
-                    eh0_2::blocking::i2c::Operation::Write(buff) => {
-                        eh1_0::i2c::Operation::Write(buff)
-                    }
-                })
-                .collect();
+            let mut ops: alloc::vec::Vec<eh1_0::i2c::Operation> =
+                operations.iter_mut().map(Convert::convert).collect();
             self.inner.transaction(address, &mut ops)
         }
     }
@@ -0,0 +1,82 @@
+use embedded_hal_compat::convert::Convert;
+
+#[test]
+fn can_convert_spi_modes() {
+    let modes = [
+        (eh0_2::spi::MODE_0, eh1_0::spi::MODE_0),
+        (eh0_2::spi::MODE_1, eh1_0::spi::MODE_1),
+        (eh0_2::spi::MODE_2, eh1_0::spi::MODE_2),
+        (eh0_2::spi::MODE_3, eh1_0::spi::MODE_3),
+    ];
+
+    for (mode_0_2, mode_1_0) in modes {
+        assert_eq!(Convert::<eh1_0::spi::Mode>::convert(mode_0_2), mode_1_0);
+        assert!(Convert::<eh0_2::spi::Mode>::convert(mode_1_0) == mode_0_2);
+
+        let polarity: eh1_0::spi::Polarity = mode_0_2.polarity.convert();
+        assert_eq!(polarity, mode_1_0.polarity);
+        let polarity: eh0_2::spi::Polarity = mode_1_0.polarity.convert();
+        assert!(polarity == mode_0_2.polarity);
+
+        let phase: eh1_0::spi::Phase = mode_0_2.phase.convert();
+        assert_eq!(phase, mode_1_0.phase);
+        let phase: eh0_2::spi::Phase = mode_1_0.phase.convert();
+        assert!(phase == mode_0_2.phase);
+    }
+}
+
+#[test]
+fn can_convert_pin_states() {
+    let states = [
+        (
+            eh0_2::digital::v2::PinState::Low,
+            eh1_0::digital::PinState::Low,
+        ),
+        (
+            eh0_2::digital::v2::PinState::High,
+            eh1_0::digital::PinState::High,
+        ),
+    ];
+
+    for (state_0_2, state_1_0) in states {
+        let state: eh1_0::digital::PinState = state_0_2.convert();
+        assert_eq!(state, state_1_0);
+        let state: eh0_2::digital::v2::PinState = state_1_0.convert();
+        assert_eq!(state, state_0_2);
+    }
+}
+
+#[test]
+fn can_convert_i2c_operations() {
+    let mut read = [0u8; 2];
+
+    let op: eh1_0::i2c::Operation = eh0_2::blocking::i2c::Operation::Read(&mut read).convert();
+    assert!(matches!(op, eh1_0::i2c::Operation::Read(buff) if buff.len() == 2));
+    let op: eh0_2::blocking::i2c::Operation = eh1_0::i2c::Operation::Write(&[1, 2, 3]).convert();
+    assert!(matches!(
+        op,
+        eh0_2::blocking::i2c::Operation::Write(&[1, 2, 3])
+    ));
+
+    // Reborrow operations in place, writing through the converted read buffers
+    let mut ops_0_2 = [
+        eh0_2::blocking::i2c::Operation::Write(&[4]),
+        eh0_2::blocking::i2c::Operation::Read(&mut read),
+    ];
+    for op in ops_0_2.iter_mut() {
+        match op.convert() {
+            eh1_0::i2c::Operation::Read(buff) => buff.fill(5),
+            eh1_0::i2c::Operation::Write(buff) => assert_eq!(buff, [4]),
+        }
+    }
+
+    let mut ops_1_0 = [eh1_0::i2c::Operation::Read(&mut read)];
+    for op in ops_1_0.iter_mut() {
+        match op.convert() {
+            eh0_2::blocking::i2c::Operation::Read(buff) => buff[0] = 6,
+            eh0_2::blocking::i2c::Operation::Write(_) => panic!(),
+        }
+    }
+
+    assert_eq!(read, [6, 5]);
+}
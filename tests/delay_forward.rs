@@ -1,4 +1,13 @@
-use embedded_hal_compat::ForwardCompat;
+use core::future::Future;
+use core::num::NonZeroU32;
+use core::pin::pin;
+use core::task::Context;
+use eh1_0::delay::DelayNs;
+use embedded_hal_compat::{
+    markers::*, DelayCalibration, ForwardCalibratedDelay, ForwardCompat, ForwardCountDown,
+    ForwardDelay,
+};
+use futures::executor::block_on;
 
 struct Peripheral;
 
@@ -17,3 +26,180 @@ fn can_forward() {
     eh1_0::delay::DelayNs::delay_ms(&mut periph_1_0, 0);
     eh1_0::delay::DelayNs::delay_ms(&mut periph_1_0, 0);
 }
+
+#[derive(Default)]
+struct Recorder {
+    ms: Vec<u32>,
+    us: Vec<u32>,
+}
+
+impl eh0_2::blocking::delay::DelayMs<u32> for Recorder {
+    fn delay_ms(&mut self, ms: u32) {
+        self.ms.push(ms);
+    }
+}
+
+impl eh0_2::blocking::delay::DelayUs<u32> for Recorder {
+    fn delay_us(&mut self, us: u32) {
+        self.us.push(us);
+    }
+}
+
+impl eh0_2::blocking::delay::DelayUs<u16> for Recorder {
+    fn delay_us(&mut self, us: u16) {
+        self.us.push(us as u32);
+    }
+}
+
+impl eh0_2::blocking::delay::DelayUs<u8> for Recorder {
+    fn delay_us(&mut self, us: u8) {
+        self.us.push(us as u32);
+    }
+}
+
+#[test]
+fn can_forward_delay_us_ms() {
+    let mut delay: ForwardDelay<_, ForwardDelayUsMs> = ForwardDelay::new(Recorder::default());
+    DelayNs::delay_ms(&mut delay, 5_000_000);
+    DelayNs::delay_us(&mut delay, 10);
+    DelayNs::delay_ns(&mut delay, 1_001);
+    assert_eq!(delay.inner().ms, [5_000_000]);
+    assert_eq!(delay.inner().us, [10, 2]);
+}
+
+#[test]
+fn can_forward_delay_ms() {
+    let mut delay: ForwardDelay<_, ForwardDelayMs> = ForwardDelay::new(Recorder::default());
+    DelayNs::delay_ms(&mut delay, u32::MAX);
+    DelayNs::delay_us(&mut delay, 1_001);
+    DelayNs::delay_ns(&mut delay, 1);
+    assert_eq!(delay.inner().ms, [u32::MAX, 2, 1]);
+}
+
+#[test]
+fn can_forward_delay_us16() {
+    let mut delay: ForwardDelay<_, ForwardDelayUs16> = ForwardDelay::new(Recorder::default());
+    DelayNs::delay_us(&mut delay, 150_000);
+    assert_eq!(delay.inner().us, [65_535, 65_535, 18_930]);
+
+    let mut delay: ForwardDelay<_, ForwardDelayUs16> = ForwardDelay::new(Recorder::default());
+    DelayNs::delay_ms(&mut delay, 100);
+    assert_eq!(delay.inner().us, [65_000, 35_000]);
+
+    // Delays beyond u32::MAX microseconds are chunked without overflow
+    let mut delay: ForwardDelay<_, ForwardDelayUs16> = ForwardDelay::new(Recorder::default());
+    DelayNs::delay_ms(&mut delay, 5_000_000);
+    assert_eq!(
+        delay.inner().us.iter().map(|us| *us as u64).sum::<u64>(),
+        5_000_000_000
+    );
+}
+
+#[test]
+fn can_forward_delay_us8() {
+    let mut delay: ForwardDelay<_, ForwardDelayUs8> = ForwardDelay::new(Recorder::default());
+    DelayNs::delay_us(&mut delay, 600);
+    assert_eq!(delay.inner().us, [255, 255, 90]);
+
+    let mut delay: ForwardDelay<_, ForwardDelayUs8> = ForwardDelay::new(Recorder::default());
+    DelayNs::delay_ms(&mut delay, 2);
+    assert_eq!(delay.inner().us, [250; 8]);
+}
+
+/// Count down timer with `Time` in microseconds, blocking once per count down
+#[derive(Default)]
+struct Timer {
+    starts: Vec<u32>,
+    pending: bool,
+    waits: usize,
+}
+
+impl eh0_2::timer::CountDown for Timer {
+    type Time = u32;
+
+    fn start<T>(&mut self, count: T)
+    where
+        T: Into<Self::Time>,
+    {
+        self.starts.push(count.into());
+        self.pending = true;
+    }
+
+    fn wait(&mut self) -> nb::Result<(), void::Void> {
+        self.waits += 1;
+        if self.pending {
+            self.pending = false;
+            return Err(nb::Error::WouldBlock);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn can_forward_count_down() {
+    // Timer supports periods of up to 1ms
+    let mut delay = ForwardCountDown::new(
+        Timer::default(),
+        NonZeroU32::new(1_000_000).unwrap(),
+        |ns| ns.div_ceil(1000),
+    );
+    DelayNs::delay_ns(&mut delay, 1);
+    DelayNs::delay_us(&mut delay, 2_500);
+    assert_eq!(delay.inner().starts, [1, 1000, 1000, 500]);
+    assert_eq!(delay.inner().waits, 8);
+}
+
+#[test]
+fn can_forward_count_down_async() {
+    let mut delay = ForwardCountDown::new(
+        Timer::default(),
+        NonZeroU32::new(1_000_000).unwrap(),
+        |ns| ns.div_ceil(1000),
+    );
+
+    {
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = pin!(eh1_0_async::delay::DelayNs::delay_us(&mut delay, 1_500));
+
+        // Yields to the executor while the timer is running
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+        assert!(fut.as_mut().poll(&mut cx).is_ready());
+    }
+    assert_eq!(delay.inner().starts, [1000, 500]);
+
+    block_on(eh1_0_async::delay::DelayNs::delay_ms(&mut delay, 2));
+    assert_eq!(delay.inner().starts, [1000, 500, 1000, 1000]);
+}
+
+/// Calibration for a 48MHz spin loop, recording requested cycles
+#[derive(Default)]
+struct Calibration {
+    cycles: Vec<u32>,
+}
+
+impl DelayCalibration for Calibration {
+    fn cycles_per_us(&self) -> u32 {
+        48
+    }
+
+    fn delay_cycles(&mut self, cycles: u32) {
+        self.cycles.push(cycles);
+    }
+}
+
+#[test]
+fn can_forward_calibrated_delay() {
+    let mut delay = ForwardCalibratedDelay::new(Recorder::default(), Calibration::default());
+    DelayNs::delay_ns(&mut delay, 50);
+    DelayNs::delay_ns(&mut delay, 999);
+    DelayNs::delay_ns(&mut delay, 1_000);
+    DelayNs::delay_ns(&mut delay, 1_001);
+    DelayNs::delay_ns(&mut delay, 1_050);
+    DelayNs::delay_us(&mut delay, 7);
+
+    let (recorder, calibration) = delay.unwrap();
+    assert_eq!(calibration.cycles, [3, 48, 1, 3]);
+    assert_eq!(recorder.us, [1, 1, 1, 7]);
+}
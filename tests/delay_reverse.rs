@@ -13,3 +13,59 @@ fn can_reverse() {
     eh0_2::blocking::delay::DelayMs::delay_ms(&mut periph_0_2, 0_u32);
     eh0_2::blocking::delay::DelayUs::delay_us(&mut periph_0_2, 0_u32);
 }
+
+#[derive(Default)]
+struct Recorder {
+    ms: Vec<u32>,
+    us: Vec<u32>,
+}
+
+impl eh1_0::delay::DelayNs for Recorder {
+    fn delay_ns(&mut self, _ns: u32) {
+        unreachable!()
+    }
+
+    fn delay_us(&mut self, us: u32) {
+        self.us.push(us);
+    }
+
+    fn delay_ms(&mut self, ms: u32) {
+        self.ms.push(ms);
+    }
+}
+
+#[test]
+fn reverse_delay_ms_boundaries() {
+    let mut periph_0_2 = Recorder::default().reverse();
+    eh0_2::blocking::delay::DelayMs::delay_ms(&mut periph_0_2, u8::MAX);
+    eh0_2::blocking::delay::DelayMs::delay_ms(&mut periph_0_2, u16::MAX);
+    eh0_2::blocking::delay::DelayMs::delay_ms(&mut periph_0_2, u32::MAX);
+    eh0_2::blocking::delay::DelayMs::delay_ms(&mut periph_0_2, 0_u64);
+    eh0_2::blocking::delay::DelayMs::delay_ms(&mut periph_0_2, u32::MAX as u64 + 1);
+    assert_eq!(
+        periph_0_2.inner().ms,
+        [u8::MAX as u32, u16::MAX as u32, u32::MAX, u32::MAX, 1]
+    );
+    assert!(periph_0_2.inner().us.is_empty());
+}
+
+#[test]
+fn reverse_delay_us_boundaries() {
+    let mut periph_0_2 = Recorder::default().reverse();
+    eh0_2::blocking::delay::DelayUs::delay_us(&mut periph_0_2, u8::MAX);
+    eh0_2::blocking::delay::DelayUs::delay_us(&mut periph_0_2, u16::MAX);
+    eh0_2::blocking::delay::DelayUs::delay_us(&mut periph_0_2, u32::MAX);
+    eh0_2::blocking::delay::DelayUs::delay_us(&mut periph_0_2, u32::MAX as u64 * 2 + 3);
+    assert_eq!(
+        periph_0_2.inner().us,
+        [
+            u8::MAX as u32,
+            u16::MAX as u32,
+            u32::MAX,
+            u32::MAX,
+            u32::MAX,
+            3
+        ]
+    );
+    assert!(periph_0_2.inner().ms.is_empty());
+}
@@ -1,4 +1,4 @@
-use embedded_hal_compat::{markers::*, Forward, ForwardCompat};
+use embedded_hal_compat::{markers::*, Forward, ForwardCompat, ForwardPinCompat};
 
 #[derive(Debug)]
 enum InputPinError {
@@ -93,3 +93,202 @@ fn output_pin_forward() {
     let mut periph_1_0 = periph_0_2.forward();
     assert!(eh1_0::digital::OutputPin::set_high(&mut periph_1_0).is_ok());
 }
+
+struct StatefulPin {
+    state: bool,
+    toggles: usize,
+}
+
+impl eh0_2::digital::v2::OutputPin for StatefulPin {
+    type Error = OutputPinError;
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.state = true;
+        Ok(())
+    }
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.state = false;
+        Ok(())
+    }
+}
+
+impl eh0_2::digital::v2::StatefulOutputPin for StatefulPin {
+    fn is_set_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.state)
+    }
+    fn is_set_low(&self) -> Result<bool, Self::Error> {
+        Ok(!self.state)
+    }
+}
+
+impl eh0_2::digital::v2::ToggleableOutputPin for StatefulPin {
+    type Error = OutputPinError;
+
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        self.state = !self.state;
+        self.toggles += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn stateful_output_pin_forward() {
+    let periph_0_2 = StatefulPin {
+        state: false,
+        toggles: 0,
+    };
+    let mut periph_1_0: Forward<_, ForwardOutputPin> = periph_0_2.forward();
+    assert!(eh1_0::digital::StatefulOutputPin::is_set_low(&mut periph_1_0).unwrap());
+    assert!(eh1_0::digital::OutputPin::set_high(&mut periph_1_0).is_ok());
+    assert!(eh1_0::digital::StatefulOutputPin::is_set_high(&mut periph_1_0).unwrap());
+
+    // Default read-modify-write toggle
+    assert!(eh1_0::digital::StatefulOutputPin::toggle(&mut periph_1_0).is_ok());
+    assert!(eh1_0::digital::StatefulOutputPin::is_set_low(&mut periph_1_0).unwrap());
+    assert_eq!(periph_1_0.inner().toggles, 0);
+}
+
+#[test]
+fn toggleable_output_pin_forward() {
+    let periph_0_2 = StatefulPin {
+        state: false,
+        toggles: 0,
+    };
+    let mut periph_1_0: Forward<_, ForwardToggleableOutputPin> = periph_0_2.forward();
+    assert!(eh1_0::digital::StatefulOutputPin::toggle(&mut periph_1_0).is_ok());
+    assert!(eh1_0::digital::StatefulOutputPin::is_set_high(&mut periph_1_0).unwrap());
+    assert!(eh1_0::digital::StatefulOutputPin::toggle(&mut periph_1_0).is_ok());
+    assert!(eh1_0::digital::StatefulOutputPin::is_set_low(&mut periph_1_0).unwrap());
+
+    // Native toggle is used
+    assert_eq!(periph_1_0.inner().toggles, 2);
+}
+
+struct V1Pin;
+
+#[allow(deprecated)]
+impl eh0_2::digital::v1::InputPin for V1Pin {
+    fn is_high(&self) -> bool {
+        true
+    }
+    fn is_low(&self) -> bool {
+        false
+    }
+}
+
+#[allow(deprecated)]
+impl eh0_2::digital::v1::OutputPin for V1Pin {
+    fn set_high(&mut self) {}
+    fn set_low(&mut self) {}
+}
+
+#[test]
+fn v1_pin_forward() {
+    let mut input: Forward<_, ForwardV1InputPin> = V1Pin.forward();
+    assert_eq!(eh1_0::digital::InputPin::is_high(&mut input), Ok(true));
+
+    let mut output: Forward<_, ForwardV1OutputPin> = V1Pin.forward();
+    assert_eq!(eh1_0::digital::OutputPin::set_high(&mut output), Ok(()));
+}
+
+#[test]
+fn pin_forward_helpers() {
+    let mut input = InputPin.forward_input();
+    assert!(eh1_0::digital::InputPin::is_high(&mut input).unwrap());
+
+    let mut output = OutputPin.forward_output();
+    assert!(eh1_0::digital::OutputPin::set_high(&mut output).is_ok());
+
+    let mut io = IoPin.forward_io();
+    assert!(eh1_0::digital::OutputPin::set_high(&mut io).is_ok());
+    assert!(eh1_0::digital::InputPin::is_high(&mut io).unwrap());
+}
+
+#[test]
+fn io_pin_conversions() {
+    let io = IoPin.forward_io();
+
+    let mut input: Forward<_, ForwardInputPin> = io.into();
+    assert!(eh1_0::digital::InputPin::is_high(&mut input).unwrap());
+
+    let io: Forward<_, ForwardIoPin> = input.into();
+    let mut output: Forward<_, ForwardOutputPin> = io.into();
+    assert!(eh1_0::digital::OutputPin::set_high(&mut output).is_ok());
+}
+
+struct TypestateInput;
+
+struct TypestateOutput {
+    state: bool,
+}
+
+impl eh0_2::digital::v2::InputPin for TypestateInput {
+    type Error = InputPinError;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+}
+
+impl eh0_2::digital::v2::OutputPin for TypestateOutput {
+    type Error = InputPinError;
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.state = true;
+        Ok(())
+    }
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.state = false;
+        Ok(())
+    }
+}
+
+impl eh0_2::digital::v2::IoPin<TypestateInput, TypestateOutput> for TypestateInput {
+    type Error = InputPinError;
+
+    fn into_input_pin(self) -> Result<TypestateInput, Self::Error> {
+        Ok(self)
+    }
+    fn into_output_pin(
+        self,
+        state: eh0_2::digital::v2::PinState,
+    ) -> Result<TypestateOutput, Self::Error> {
+        Ok(TypestateOutput {
+            state: state == eh0_2::digital::v2::PinState::High,
+        })
+    }
+}
+
+impl eh0_2::digital::v2::IoPin<TypestateInput, TypestateOutput> for TypestateOutput {
+    type Error = InputPinError;
+
+    fn into_input_pin(self) -> Result<TypestateInput, Self::Error> {
+        Ok(TypestateInput)
+    }
+    fn into_output_pin(
+        mut self,
+        state: eh0_2::digital::v2::PinState,
+    ) -> Result<TypestateOutput, Self::Error> {
+        self.state = state == eh0_2::digital::v2::PinState::High;
+        Ok(self)
+    }
+}
+
+#[test]
+fn typestate_io_pin_forward() {
+    let mut input = TypestateInput.forward_input();
+    assert!(eh1_0::digital::InputPin::is_high(&mut input).unwrap());
+
+    let mut output = input
+        .into_output_pin(eh1_0::digital::PinState::High)
+        .unwrap();
+    assert!(output.inner().state);
+    assert!(eh1_0::digital::OutputPin::set_low(&mut output).is_ok());
+    assert!(!output.inner().state);
+
+    let mut input = output.into_input_pin().unwrap();
+    assert!(eh1_0::digital::InputPin::is_high(&mut input).unwrap());
+}
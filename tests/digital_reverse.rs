@@ -1,6 +1,6 @@
-use embedded_hal_compat::ReverseCompat;
+use embedded_hal_compat::{CellError, ReverseCompat, ReverseIoInput, V1ErrorPolicy};
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 enum PinError {
     _Something,
 }
@@ -42,3 +42,202 @@ fn can_reverse() {
     assert!(eh0_2::digital::v2::OutputPin::set_high(&mut periph_0_2).is_ok());
     assert!(eh0_2::digital::v2::InputPin::is_high(&periph_0_2).unwrap());
 }
+
+struct StatefulPeripheral {
+    state: bool,
+}
+
+impl eh1_0::digital::ErrorType for StatefulPeripheral {
+    type Error = PinError;
+}
+
+impl eh1_0::digital::OutputPin for StatefulPeripheral {
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.state = true;
+        Ok(())
+    }
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.state = false;
+        Ok(())
+    }
+}
+
+impl eh1_0::digital::StatefulOutputPin for StatefulPeripheral {
+    fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.state)
+    }
+    fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.state)
+    }
+}
+
+#[test]
+fn can_reverse_try_cell() {
+    let periph_1_0 = StatefulPeripheral { state: false };
+    let mut periph_0_2 = periph_1_0.reverse_try_cell();
+    assert!(eh0_2::digital::v2::OutputPin::set_high(&mut periph_0_2).is_ok());
+    assert!(eh0_2::digital::v2::StatefulOutputPin::is_set_high(&periph_0_2).unwrap());
+
+    // Conflicting borrows return an error rather than panicking
+    let _borrow = periph_0_2.inner().borrow_mut();
+    assert_eq!(
+        eh0_2::digital::v2::StatefulOutputPin::is_set_high(&periph_0_2),
+        Err(CellError::BorrowConflict)
+    );
+}
+
+#[test]
+fn reverse_try_cell_pin_errors() {
+    let periph_0_2 = FailingPeripheral.reverse_try_cell();
+    assert_eq!(
+        eh0_2::digital::v2::InputPin::is_high(&periph_0_2),
+        Err(CellError::Pin(PinError::_Something))
+    );
+}
+
+#[test]
+fn can_reverse_output_without_cell() {
+    let periph_1_0 = StatefulPeripheral { state: false };
+    let mut periph_0_2 = periph_1_0.reverse();
+    assert!(eh0_2::digital::v2::OutputPin::set_high(&mut periph_0_2).is_ok());
+    assert!(periph_0_2.inner().state);
+    assert!(eh0_2::digital::v2::ToggleableOutputPin::toggle(&mut periph_0_2).is_ok());
+    assert!(!periph_0_2.inner().state);
+}
+
+#[test]
+fn can_reverse_stateful() {
+    let periph_1_0 = StatefulPeripheral { state: false };
+    let mut periph_0_2 = periph_1_0.reverse_cell();
+    assert!(eh0_2::digital::v2::StatefulOutputPin::is_set_low(&periph_0_2).unwrap());
+    assert!(eh0_2::digital::v2::OutputPin::set_high(&mut periph_0_2).is_ok());
+    assert!(eh0_2::digital::v2::StatefulOutputPin::is_set_high(&periph_0_2).unwrap());
+    assert!(eh0_2::digital::v2::ToggleableOutputPin::toggle(&mut periph_0_2).is_ok());
+    assert!(eh0_2::digital::v2::StatefulOutputPin::is_set_low(&periph_0_2).unwrap());
+    assert!(!periph_0_2.inner().borrow().state);
+}
+
+struct FailingPeripheral;
+
+impl eh1_0::digital::ErrorType for FailingPeripheral {
+    type Error = PinError;
+}
+
+impl eh1_0::digital::OutputPin for FailingPeripheral {
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Err(PinError::_Something)
+    }
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Err(PinError::_Something)
+    }
+}
+
+impl eh1_0::digital::InputPin for FailingPeripheral {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Err(PinError::_Something)
+    }
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Err(PinError::_Something)
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn can_reverse_v1() {
+    let mut periph_0_2 = Peripheral.reverse_v1(V1ErrorPolicy::Panic);
+    eh0_2::digital::v1::OutputPin::set_high(&mut periph_0_2);
+    assert!(eh0_2::digital::v1::InputPin::is_high(&periph_0_2));
+    assert!(periph_0_2.take_error().is_none());
+}
+
+#[test]
+#[allow(deprecated)]
+fn reverse_v1_latches_errors() {
+    let mut periph_0_2 = FailingPeripheral.reverse_v1(V1ErrorPolicy::Latch);
+    eh0_2::digital::v1::OutputPin::set_high(&mut periph_0_2);
+    assert_eq!(periph_0_2.take_error(), Some(PinError::_Something));
+    assert!(periph_0_2.take_error().is_none());
+    assert!(!eh0_2::digital::v1::InputPin::is_high(&periph_0_2));
+    assert_eq!(periph_0_2.take_error(), Some(PinError::_Something));
+}
+
+#[test]
+#[allow(deprecated)]
+fn reverse_v1_ignores_errors() {
+    let mut periph_0_2 = FailingPeripheral.reverse_v1(V1ErrorPolicy::Ignore);
+    eh0_2::digital::v1::OutputPin::set_low(&mut periph_0_2);
+    assert!(!eh0_2::digital::v1::InputPin::is_low(&periph_0_2));
+    assert!(periph_0_2.take_error().is_none());
+}
+
+#[test]
+#[should_panic]
+#[allow(deprecated)]
+fn reverse_v1_panics_on_error() {
+    let mut periph_0_2 = FailingPeripheral.reverse_v1(V1ErrorPolicy::Panic);
+    eh0_2::digital::v1::OutputPin::set_high(&mut periph_0_2);
+}
+
+struct InputPeripheral;
+
+impl eh1_0::digital::ErrorType for InputPeripheral {
+    type Error = PinError;
+}
+
+impl eh1_0::digital::InputPin for InputPeripheral {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+}
+
+#[test]
+fn can_reverse_io_pin() {
+    use eh0_2::digital::v2::{InputPin, IoPin, OutputPin, PinState};
+
+    let periph_0_2 = ReverseIoInput::new(
+        InputPeripheral,
+        |_output: StatefulPeripheral| Ok::<_, PinError>(InputPeripheral),
+        |_input, state| {
+            Ok(StatefulPeripheral {
+                state: state == eh1_0::digital::PinState::High,
+            })
+        },
+    );
+    assert!(periph_0_2.is_high().unwrap());
+
+    let mut periph_0_2 = periph_0_2.into_output_pin(PinState::High).unwrap();
+    assert!(periph_0_2.set_low().is_ok());
+
+    let periph_0_2 = periph_0_2.into_output_pin(PinState::High).unwrap();
+    let periph_0_2 = periph_0_2.into_input_pin().unwrap();
+    assert!(periph_0_2.is_high().unwrap());
+}
+
+#[cfg(feature = "critical-section")]
+mod cs {
+    use super::{Peripheral, StatefulPeripheral};
+    use embedded_hal_compat::{Reverse, ReverseCompat, ReverseCs};
+
+    static PIN: Reverse<ReverseCs<Peripheral>> = Reverse::new(ReverseCs::new(Peripheral));
+
+    #[test]
+    fn can_reverse_cs_static() {
+        let mut pin = &PIN;
+        assert!(eh0_2::digital::v2::OutputPin::set_high(&mut pin).is_ok());
+        assert!(eh0_2::digital::v2::InputPin::is_high(&PIN).unwrap());
+    }
+
+    #[test]
+    fn can_reverse_cs() {
+        let periph_1_0 = StatefulPeripheral { state: false };
+        let mut periph_0_2 = periph_1_0.reverse_cs();
+        assert!(eh0_2::digital::v2::OutputPin::set_high(&mut periph_0_2).is_ok());
+        assert!(eh0_2::digital::v2::StatefulOutputPin::is_set_high(&periph_0_2).unwrap());
+        assert!(eh0_2::digital::v2::ToggleableOutputPin::toggle(&mut periph_0_2).is_ok());
+        assert!(eh0_2::digital::v2::StatefulOutputPin::is_set_low(&periph_0_2).unwrap());
+        assert!(!periph_0_2.inner().lock(|pin| pin.state));
+    }
+}
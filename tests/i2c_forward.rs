@@ -1,4 +1,5 @@
 use embedded_hal_compat::ForwardCompat;
+use futures::executor::block_on;
 
 #[derive(Debug)]
 enum ImplError {
@@ -95,3 +96,28 @@ fn can_forward() {
     assert!(eh1_0::i2c::I2c::read(&mut periph_1_0, 0, &mut data).is_ok());
     assert!(eh1_0::i2c::I2c::transaction(&mut periph_1_0, 0, &mut []).is_ok());
 }
+
+#[test]
+fn can_forward_async() {
+    let mut data = [];
+    let periph_0_2 = Peripheral;
+    let mut periph_1_0 = periph_0_2.forward();
+    block_on(async {
+        assert!(eh1_0_async::i2c::I2c::write(&mut periph_1_0, 0, &[])
+            .await
+            .is_ok());
+        assert!(
+            eh1_0_async::i2c::I2c::write_read(&mut periph_1_0, 0, &[], &mut data)
+                .await
+                .is_ok()
+        );
+        assert!(eh1_0_async::i2c::I2c::read(&mut periph_1_0, 0, &mut data)
+            .await
+            .is_ok());
+        assert!(
+            eh1_0_async::i2c::I2c::transaction(&mut periph_1_0, 0, &mut [])
+                .await
+                .is_ok()
+        );
+    });
+}
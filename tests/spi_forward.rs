@@ -1,4 +1,13 @@
-use embedded_hal_compat::ForwardCompat;
+use core::num::NonZeroUsize;
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use eh1_0::spi::{Error, ErrorKind, Operation, SpiBus, SpiDevice};
+use embedded_hal_compat::{
+    markers::{ForwardSpiU16, ForwardSpiU32},
+    Forward, ForwardCompat, ForwardSpiCompat, ForwardSpiConfig, ForwardSpiDevice,
+    ForwardTransactionalDevice, SpiDeviceError, TransactionalError, WriteOnlyError,
+};
+use futures::executor::block_on;
 
 #[derive(Debug)]
 enum ImplError {
@@ -58,3 +67,708 @@ fn can_forward() {
     assert!(eh1_0::spi::SpiBus::transfer(&mut periph_1_0, &mut [], &[]).is_ok());
     assert!(eh1_0::spi::SpiBus::transfer_in_place(&mut periph_1_0, &mut []).is_ok());
 }
+
+/// Mock bus recording clocked out words and reading back their complement
+#[derive(Default)]
+struct Loopback {
+    clocked: Vec<u8>,
+    chunks: Vec<usize>,
+    flushed: bool,
+}
+
+impl eh0_2::blocking::spi::Write<u8> for Loopback {
+    type Error = ImplError;
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.clocked.extend_from_slice(words);
+        self.chunks.push(words.len());
+        Ok(())
+    }
+}
+
+impl eh0_2::blocking::spi::Transfer<u8> for Loopback {
+    type Error = ImplError;
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        self.clocked.extend_from_slice(words);
+        self.chunks.push(words.len());
+        for w in words.iter_mut() {
+            *w = !*w;
+        }
+        Ok(words)
+    }
+}
+
+#[test]
+fn transfer_handles_unequal_lengths() {
+    for read_len in 0..=4 {
+        for write_len in 0..=4 {
+            let mut bus = Loopback::default().forward();
+            let write: Vec<u8> = (1..=write_len as u8).collect();
+            let mut read = vec![0xAA; read_len];
+
+            bus.transfer(&mut read, &write).unwrap();
+
+            // Writes are padded with empty bytes to the longer of the two buffers
+            let mut expected = write.clone();
+            expected.resize(read_len.max(write_len), 0x00);
+            assert_eq!(
+                bus.inner().clocked,
+                expected,
+                "read: {read_len} write: {write_len}"
+            );
+
+            // Reads capture the first words clocked in, surplus reads are discarded
+            let expected: Vec<u8> = expected[..read_len].iter().map(|w| !w).collect();
+            assert_eq!(read, expected, "read: {read_len} write: {write_len}");
+        }
+    }
+}
+
+#[test]
+fn can_forward_with_config() {
+    let mut bus = Loopback::default().forward_with(ForwardSpiConfig {
+        fill: 0xFF,
+        flush: Some(|bus: &mut Loopback| {
+            bus.flushed = true;
+            Ok(())
+        }),
+        max_chunk: NonZeroUsize::new(2),
+    });
+
+    let mut read = [0u8; 3];
+    bus.read(&mut read).unwrap();
+    assert_eq!(read, [0x00; 3]);
+    bus.write(&[1, 2, 3, 4, 5]).unwrap();
+    bus.transfer(&mut read, &[6]).unwrap();
+    assert_eq!(read, [!6, 0x00, 0x00]);
+    bus.transfer_in_place(&mut [7, 8, 9]).unwrap();
+
+    assert!(!bus.inner().flushed);
+    bus.flush().unwrap();
+
+    let bus = bus.unwrap();
+    assert!(bus.flushed);
+    assert_eq!(
+        bus.clocked,
+        [0xFF, 0xFF, 0xFF, 1, 2, 3, 4, 5, 6, 0xFF, 0xFF, 7, 8, 9]
+    );
+    assert_eq!(bus.chunks, [2, 1, 2, 2, 1, 1, 2, 2, 1]);
+}
+
+#[test]
+fn can_forward_with_default_config() {
+    let mut bus = Loopback::default().forward_with(ForwardSpiConfig::default());
+    let mut read = [0u8; 3];
+    bus.read(&mut read).unwrap();
+    bus.flush().unwrap();
+
+    let bus = bus.unwrap();
+    assert!(!bus.flushed);
+    assert_eq!(bus.clocked, [0x00; 3]);
+    assert_eq!(bus.chunks, [3]);
+}
+
+/// Mock bus receiving into an internal buffer rather than in place
+struct Dma {
+    /// Receive from the internal buffer when set, otherwise from a sub-slice of the input
+    separate: bool,
+}
+
+static DMA_BUFFER: [u8; 8] = [0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17];
+
+impl eh0_2::blocking::spi::Write<u8> for Dma {
+    type Error = ImplError;
+    fn write(&mut self, _words: &[u8]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl eh0_2::blocking::spi::Transfer<u8> for Dma {
+    type Error = ImplError;
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        match self.separate {
+            true => Ok(&DMA_BUFFER[..words.len()]),
+            false => Ok(&words[1..]),
+        }
+    }
+}
+
+#[test]
+fn transfer_copies_back_separate_buffer() {
+    let mut bus = Dma { separate: true }.forward();
+
+    let mut read = [0u8; 4];
+    bus.read(&mut read).unwrap();
+    assert_eq!(read, [0x10, 0x11, 0x12, 0x13]);
+
+    let mut read = [0u8; 3];
+    bus.transfer(&mut read, &[1, 2]).unwrap();
+    assert_eq!(read, [0x10, 0x11, 0x10]);
+
+    let mut words = [1, 2, 3];
+    bus.transfer_in_place(&mut words).unwrap();
+    assert_eq!(words, [0x10, 0x11, 0x12]);
+
+    let mut bus = Dma { separate: true }.forward_with(ForwardSpiConfig {
+        max_chunk: NonZeroUsize::new(2),
+        ..Default::default()
+    });
+    let mut words = [1, 2, 3];
+    bus.transfer_in_place(&mut words).unwrap();
+    assert_eq!(words, [0x10, 0x11, 0x10]);
+}
+
+#[test]
+fn transfer_copies_back_sub_slice() {
+    let mut bus = Dma { separate: false }.forward();
+
+    let mut words = [1, 2, 3];
+    bus.transfer_in_place(&mut words).unwrap();
+    assert_eq!(words, [2, 3, 3]);
+}
+
+/// Mock bus with 16 and 32-bit words, otherwise as [Loopback]
+#[derive(Default)]
+struct Wide {
+    clocked: Vec<u32>,
+}
+
+impl eh0_2::blocking::spi::Write<u16> for Wide {
+    type Error = ImplError;
+    fn write(&mut self, words: &[u16]) -> Result<(), Self::Error> {
+        self.clocked.extend(words.iter().map(|w| *w as u32));
+        Ok(())
+    }
+}
+
+impl eh0_2::blocking::spi::Transfer<u16> for Wide {
+    type Error = ImplError;
+    fn transfer<'w>(&mut self, words: &'w mut [u16]) -> Result<&'w [u16], Self::Error> {
+        self.clocked.extend(words.iter().map(|w| *w as u32));
+        words.iter_mut().for_each(|w| *w = !*w);
+        Ok(words)
+    }
+}
+
+impl eh0_2::blocking::spi::Write<u32> for Wide {
+    type Error = ImplError;
+    fn write(&mut self, words: &[u32]) -> Result<(), Self::Error> {
+        self.clocked.extend_from_slice(words);
+        Ok(())
+    }
+}
+
+impl eh0_2::blocking::spi::Transfer<u32> for Wide {
+    type Error = ImplError;
+    fn transfer<'w>(&mut self, words: &'w mut [u32]) -> Result<&'w [u32], Self::Error> {
+        self.clocked.extend_from_slice(words);
+        words.iter_mut().for_each(|w| *w = !*w);
+        Ok(words)
+    }
+}
+
+#[test]
+fn can_forward_u16() {
+    let mut bus: Forward<_, ForwardSpiU16> = Wide::default().forward();
+    bus.write(&[0x1234u16]).unwrap();
+
+    let mut read = [0u16; 3];
+    bus.read(&mut read[..1]).unwrap();
+    assert_eq!(read[0], 0xFFFF);
+    bus.transfer(&mut read, &[0xABCD, 0x00FF]).unwrap();
+    assert_eq!(read, [0x5432, 0xFF00, 0xFFFF]);
+
+    let mut words = [0x0F0Fu16];
+    bus.transfer_in_place(&mut words).unwrap();
+    assert_eq!(words, [0xF0F0]);
+    bus.flush().unwrap();
+
+    assert_eq!(
+        bus.inner().clocked,
+        [0x1234, 0x0000, 0xABCD, 0x00FF, 0x0000, 0x0F0F]
+    );
+}
+
+#[test]
+fn can_forward_u32() {
+    let mut bus: Forward<_, ForwardSpiU32> = Wide::default().forward();
+    bus.write(&[0x1234_5678u32]).unwrap();
+
+    let mut read = [0u32; 3];
+    bus.read(&mut read[..1]).unwrap();
+    assert_eq!(read[0], 0xFFFF_FFFF);
+    bus.transfer(&mut read, &[0xABCD_0123, 0x0000_FFFF])
+        .unwrap();
+    assert_eq!(read, [0x5432_FEDC, 0xFFFF_0000, 0xFFFF_FFFF]);
+
+    let mut words = [0x0F0F_0F0Fu32];
+    bus.transfer_in_place(&mut words).unwrap();
+    assert_eq!(words, [0xF0F0_F0F0]);
+    bus.flush().unwrap();
+
+    assert_eq!(
+        bus.inner().clocked,
+        [0x1234_5678, 0, 0xABCD_0123, 0x0000_FFFF, 0, 0x0F0F_0F0F]
+    );
+}
+
+/// Events shared between the mock device bus, chip select and delay
+#[derive(Debug, PartialEq)]
+enum Event {
+    Select,
+    Deselect,
+    Clocked(Vec<u8>),
+    DelayUs(u32),
+    Exec,
+}
+
+type Log = Rc<RefCell<Vec<Event>>>;
+
+/// Mock device bus logging clocked out words and reading back their complement,
+/// failing transfers when `fail` is set
+struct LoggedBus {
+    log: Log,
+    fail: bool,
+}
+
+impl eh0_2::blocking::spi::Write<u8> for LoggedBus {
+    type Error = ImplError;
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push(Event::Clocked(words.to_vec()));
+        Ok(())
+    }
+}
+
+impl eh0_2::blocking::spi::Transfer<u8> for LoggedBus {
+    type Error = ImplError;
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        if self.fail {
+            return Err(ImplError::_Something);
+        }
+        self.log.borrow_mut().push(Event::Clocked(words.to_vec()));
+        words.iter_mut().for_each(|w| *w = !*w);
+        Ok(words)
+    }
+}
+
+/// Mock chip select logging selection, failing to select when `fail` is set
+struct LoggedCs {
+    log: Log,
+    fail: bool,
+}
+
+impl eh0_2::digital::v2::OutputPin for LoggedCs {
+    type Error = ();
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        if self.fail {
+            return Err(());
+        }
+        self.log.borrow_mut().push(Event::Select);
+        Ok(())
+    }
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push(Event::Deselect);
+        Ok(())
+    }
+}
+
+struct LoggedDelay {
+    log: Log,
+}
+
+impl eh0_2::blocking::delay::DelayUs<u32> for LoggedDelay {
+    fn delay_us(&mut self, us: u32) {
+        self.log.borrow_mut().push(Event::DelayUs(us));
+    }
+}
+
+fn logged_device(
+    fail_bus: bool,
+    fail_cs: bool,
+) -> (Log, ForwardSpiDevice<LoggedBus, LoggedCs, LoggedDelay>) {
+    let log = Log::default();
+    let device = ForwardSpiDevice::new(
+        LoggedBus {
+            log: log.clone(),
+            fail: fail_bus,
+        },
+        LoggedCs {
+            log: log.clone(),
+            fail: fail_cs,
+        },
+        LoggedDelay { log: log.clone() },
+    );
+    (log, device)
+}
+
+#[test]
+fn can_forward_spi_device() {
+    let (log, mut device) = logged_device(false, false);
+
+    let mut read = [0u8; 2];
+    let mut in_place = [0x0F];
+    let mut transfer = [0u8; 1];
+    device
+        .transaction(&mut [
+            Operation::Write(&[1, 2]),
+            Operation::DelayNs(1_500),
+            Operation::Read(&mut read),
+            Operation::TransferInPlace(&mut in_place),
+            Operation::Transfer(&mut transfer, &[3, 4]),
+        ])
+        .unwrap();
+
+    assert_eq!(read, [0xFF, 0xFF]);
+    assert_eq!(in_place, [0xF0]);
+    assert_eq!(transfer, [!3]);
+    assert_eq!(
+        *log.borrow(),
+        [
+            Event::Select,
+            Event::Clocked(vec![1, 2]),
+            Event::DelayUs(2),
+            Event::Clocked(vec![0, 0]),
+            Event::Clocked(vec![0x0F]),
+            Event::Clocked(vec![3]),
+            Event::Clocked(vec![4]),
+            Event::Deselect,
+        ]
+    );
+}
+
+#[test]
+fn spi_device_releases_cs_on_bus_error() {
+    let (log, mut device) = logged_device(true, false);
+
+    let err = device.read(&mut [0u8; 1]).unwrap_err();
+    assert!(matches!(err, SpiDeviceError::Bus(ImplError::_Something)));
+    assert_eq!(err.kind(), ErrorKind::Other);
+    assert_eq!(*log.borrow(), [Event::Select, Event::Deselect]);
+}
+
+#[test]
+fn spi_device_maps_cs_errors() {
+    let (log, mut device) = logged_device(false, true);
+
+    let err = device.write(&[1]).unwrap_err();
+    assert!(matches!(err, SpiDeviceError::ChipSelect(())));
+    assert_eq!(err.kind(), ErrorKind::ChipSelectFault);
+    assert!(log.borrow().is_empty());
+}
+
+/// Mock non-blocking bus with loopback FIFOs receiving the complement of sent words,
+/// returning `WouldBlock` on every other call when `stall` is set
+#[derive(Default)]
+struct Fifo {
+    sent: Vec<u8>,
+    rx: VecDeque<u8>,
+    stall: bool,
+    busy: bool,
+}
+
+impl Fifo {
+    fn busy(&mut self) -> bool {
+        self.busy = self.stall && !self.busy;
+        self.busy
+    }
+}
+
+impl eh0_2::spi::FullDuplex<u8> for Fifo {
+    type Error = ImplError;
+
+    fn read(&mut self) -> nb::Result<u8, Self::Error> {
+        if self.busy() {
+            return Err(nb::Error::WouldBlock);
+        }
+        self.rx.pop_front().ok_or(nb::Error::WouldBlock)
+    }
+
+    fn send(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        if self.busy() {
+            return Err(nb::Error::WouldBlock);
+        }
+        self.sent.push(word);
+        self.rx.push_back(!word);
+        Ok(())
+    }
+}
+
+#[test]
+fn can_forward_full_duplex() {
+    let fifo = Fifo {
+        stall: true,
+        ..Default::default()
+    };
+    let mut bus = fifo.forward_full_duplex();
+
+    bus.write(&[1, 2]).unwrap();
+    let mut read = [0u8; 2];
+    bus.read(&mut read).unwrap();
+    assert_eq!(read, [0xFF, 0xFF]);
+
+    let mut read = [0u8; 3];
+    bus.transfer(&mut read, &[3]).unwrap();
+    assert_eq!(read, [!3, 0xFF, 0xFF]);
+    bus.transfer(&mut read[..1], &[4, 5]).unwrap();
+    assert_eq!(read[0], !4);
+
+    let mut words = [6, 7];
+    bus.transfer_in_place(&mut words).unwrap();
+    assert_eq!(words, [!6, !7]);
+
+    let fifo = bus.release();
+    assert_eq!(fifo.sent, [1, 2, 0, 0, 3, 0, 0, 4, 5, 6, 7]);
+    assert!(fifo.rx.is_empty());
+}
+
+#[test]
+fn full_duplex_flush_drains_rx() {
+    let mut fifo = Fifo::default();
+    fifo.rx.extend([1, 2, 3]);
+    let mut bus = fifo.forward_full_duplex();
+
+    bus.flush().unwrap();
+    assert!(bus.release().rx.is_empty());
+}
+
+#[test]
+fn can_forward_async() {
+    let mut bus = Loopback::default().forward();
+    block_on(async {
+        eh1_0_async::spi::SpiBus::write(&mut bus, &[1, 2])
+            .await
+            .unwrap();
+        let mut read = [0u8; 2];
+        eh1_0_async::spi::SpiBus::read(&mut bus, &mut read)
+            .await
+            .unwrap();
+        assert_eq!(read, [0xFF, 0xFF]);
+        eh1_0_async::spi::SpiBus::transfer(&mut bus, &mut read, &[3])
+            .await
+            .unwrap();
+        assert_eq!(read, [!3, 0xFF]);
+        eh1_0_async::spi::SpiBus::transfer_in_place(&mut bus, &mut read)
+            .await
+            .unwrap();
+        assert_eq!(read, [3, 0]);
+        eh1_0_async::spi::SpiBus::flush(&mut bus).await.unwrap();
+    });
+    assert_eq!(bus.inner().clocked, [1, 2, 0, 0, 3, 0, !3, 0xFF]);
+}
+
+#[test]
+fn can_forward_with_config_async() {
+    let mut bus = Loopback::default().forward_with(ForwardSpiConfig {
+        fill: 0xFF,
+        flush: Some(|bus: &mut Loopback| {
+            bus.flushed = true;
+            Ok(())
+        }),
+        max_chunk: None,
+    });
+    block_on(async {
+        let mut read = [0u8; 2];
+        eh1_0_async::spi::SpiBus::read(&mut bus, &mut read)
+            .await
+            .unwrap();
+        assert_eq!(read, [0x00, 0x00]);
+        eh1_0_async::spi::SpiBus::transfer(&mut bus, &mut read, &[1])
+            .await
+            .unwrap();
+        assert_eq!(read, [!1, 0x00]);
+        eh1_0_async::spi::SpiBus::flush(&mut bus).await.unwrap();
+    });
+
+    let bus = bus.unwrap();
+    assert!(bus.flushed);
+    assert_eq!(bus.clocked, [0xFF, 0xFF, 1, 0xFF]);
+}
+
+#[test]
+fn can_forward_spi_device_async() {
+    let (log, mut device) = logged_device(false, false);
+    let mut read = [0u8; 1];
+    block_on(async {
+        eh1_0_async::spi::SpiDevice::transaction(
+            &mut device,
+            &mut [Operation::Write(&[1]), Operation::Read(&mut read)],
+        )
+        .await
+        .unwrap();
+    });
+
+    assert_eq!(read, [0xFF]);
+    assert_eq!(
+        *log.borrow(),
+        [
+            Event::Select,
+            Event::Clocked(vec![1]),
+            Event::Clocked(vec![0]),
+            Event::Deselect,
+        ]
+    );
+}
+
+/// Mock bus implementing only `Write`
+#[derive(Default)]
+struct WriteOnly {
+    written: Vec<u8>,
+}
+
+impl eh0_2::blocking::spi::Write<u8> for WriteOnly {
+    type Error = ImplError;
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.written.extend_from_slice(words);
+        Ok(())
+    }
+}
+
+#[test]
+fn can_forward_write_only() {
+    let mut bus = WriteOnly::default().forward_write_only();
+
+    bus.write(&[1, 2, 3]).unwrap();
+    bus.flush().unwrap();
+
+    let mut words = [0u8; 2];
+    let err = bus.read(&mut words).unwrap_err();
+    assert!(matches!(err, WriteOnlyError::Unsupported));
+    assert_eq!(err.kind(), ErrorKind::Other);
+    assert!(matches!(
+        bus.transfer(&mut words, &[4]),
+        Err(WriteOnlyError::Unsupported)
+    ));
+    assert!(matches!(
+        bus.transfer_in_place(&mut words),
+        Err(WriteOnlyError::Unsupported)
+    ));
+
+    assert_eq!(bus.release().written, [1, 2, 3]);
+}
+
+impl eh0_2::blocking::spi::Transactional<u8> for LoggedBus {
+    type Error = ImplError;
+    fn exec(
+        &mut self,
+        operations: &mut [eh0_2::blocking::spi::Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push(Event::Exec);
+        for op in operations {
+            match op {
+                eh0_2::blocking::spi::Operation::Write(words) => {
+                    eh0_2::blocking::spi::Write::write(self, words)?
+                }
+                eh0_2::blocking::spi::Operation::Transfer(words) => {
+                    eh0_2::blocking::spi::Transfer::transfer(self, words)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn logged_transactional_device<const N: usize>(
+    fail_bus: bool,
+    fail_cs: bool,
+) -> (Log, ForwardTransactionalDevice<LoggedBus, LoggedCs, N>) {
+    let log = Log::default();
+    let device = ForwardTransactionalDevice::new(
+        LoggedBus {
+            log: log.clone(),
+            fail: fail_bus,
+        },
+        LoggedCs {
+            log: log.clone(),
+            fail: fail_cs,
+        },
+    );
+    (log, device)
+}
+
+#[test]
+fn can_forward_transactional_device() {
+    let (log, mut device) = logged_transactional_device::<7>(false, false);
+
+    let mut read = [0xAAu8; 2];
+    let mut in_place = [0x0F];
+    let mut short = [0u8; 1];
+    let mut long = [0u8; 3];
+    device
+        .transaction(&mut [
+            Operation::Write(&[1, 2]),
+            Operation::Read(&mut read),
+            Operation::TransferInPlace(&mut in_place),
+            Operation::Transfer(&mut short, &[3, 4]),
+            Operation::Transfer(&mut long, &[5]),
+        ])
+        .unwrap();
+
+    assert_eq!(read, [0xFF, 0xFF]);
+    assert_eq!(in_place, [0xF0]);
+    assert_eq!(short, [!3]);
+    assert_eq!(long, [!5, 0xFF, 0xFF]);
+
+    // The whole transaction runs within a single exec call
+    assert_eq!(
+        *log.borrow(),
+        [
+            Event::Select,
+            Event::Exec,
+            Event::Clocked(vec![1, 2]),
+            Event::Clocked(vec![0, 0]),
+            Event::Clocked(vec![0x0F]),
+            Event::Clocked(vec![3]),
+            Event::Clocked(vec![4]),
+            Event::Clocked(vec![5]),
+            Event::Clocked(vec![0, 0]),
+            Event::Deselect,
+        ]
+    );
+}
+
+#[test]
+fn transactional_device_rejects_unbatchable_transactions() {
+    let (log, mut device) = logged_transactional_device::<1>(false, false);
+
+    let err = device
+        .transaction(&mut [Operation::Write(&[1]), Operation::DelayNs(1_000)])
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        SpiDeviceError::Bus(TransactionalError::Unsupported)
+    ));
+    assert_eq!(err.kind(), ErrorKind::Other);
+
+    // An uneven transfer needs an operation for the surplus words
+    let mut read = [0u8; 2];
+    let err = device
+        .transaction(&mut [Operation::Transfer(&mut read, &[1])])
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        SpiDeviceError::Bus(TransactionalError::TooManyOperations)
+    ));
+
+    assert!(log.borrow().is_empty());
+}
+
+#[test]
+fn transactional_device_releases_cs_on_bus_error() {
+    let (log, mut device) = logged_transactional_device::<1>(true, false);
+
+    let err = device.read(&mut [0u8; 1]).unwrap_err();
+    assert!(matches!(
+        err,
+        SpiDeviceError::Bus(TransactionalError::Bus(ImplError::_Something))
+    ));
+    assert_eq!(*log.borrow(), [Event::Select, Event::Exec, Event::Deselect]);
+}
+
+#[test]
+fn transactional_device_maps_cs_errors() {
+    let (log, mut device) = logged_transactional_device::<1>(false, true);
+
+    let err = device.write(&[1]).unwrap_err();
+    assert!(matches!(err, SpiDeviceError::ChipSelect(())));
+    assert_eq!(err.kind(), ErrorKind::ChipSelectFault);
+    assert!(log.borrow().is_empty());
+}
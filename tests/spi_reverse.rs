@@ -85,3 +85,77 @@ fn can_perform_trasaction_reverse() {
         panic!();
     }
 }
+
+/// Mock bus with 16 and 32-bit words, recording written words and reading back their complement
+#[derive(Default)]
+struct Wide {
+    written: Vec<u32>,
+}
+
+impl eh1_0::spi::ErrorType for Wide {
+    type Error = ImplError;
+}
+
+impl eh1_0::spi::SpiBus<u16> for Wide {
+    fn read(&mut self, _words: &mut [u16]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn write(&mut self, words: &[u16]) -> Result<(), Self::Error> {
+        self.written.extend(words.iter().map(|w| *w as u32));
+        Ok(())
+    }
+    fn transfer(&mut self, _read: &mut [u16], _write: &[u16]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn transfer_in_place(&mut self, words: &mut [u16]) -> Result<(), Self::Error> {
+        words.iter_mut().for_each(|w| *w = !*w);
+        Ok(())
+    }
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl eh1_0::spi::SpiBus<u32> for Wide {
+    fn read(&mut self, _words: &mut [u32]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn write(&mut self, words: &[u32]) -> Result<(), Self::Error> {
+        self.written.extend_from_slice(words);
+        Ok(())
+    }
+    fn transfer(&mut self, _read: &mut [u32], _write: &[u32]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn transfer_in_place(&mut self, words: &mut [u32]) -> Result<(), Self::Error> {
+        words.iter_mut().for_each(|w| *w = !*w);
+        Ok(())
+    }
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[test]
+fn can_reverse_u16() {
+    let mut periph_0_2 = Wide::default().reverse();
+    eh0_2::blocking::spi::Write::write(&mut periph_0_2, &[0x1234u16, 0xABCD]).unwrap();
+
+    let mut words = [0x00FFu16, 0x1234];
+    let read = eh0_2::blocking::spi::Transfer::transfer(&mut periph_0_2, &mut words).unwrap();
+    assert_eq!(read, [0xFF00, 0xEDCB]);
+
+    assert_eq!(periph_0_2.inner().written, [0x1234, 0xABCD]);
+}
+
+#[test]
+fn can_reverse_u32() {
+    let mut periph_0_2 = Wide::default().reverse();
+    eh0_2::blocking::spi::Write::write(&mut periph_0_2, &[0x1234_5678u32]).unwrap();
+
+    let mut words = [0x0000_FFFFu32, 0x1234_5678];
+    let read = eh0_2::blocking::spi::Transfer::transfer(&mut periph_0_2, &mut words).unwrap();
+    assert_eq!(read, [0xFFFF_0000, 0xEDCB_A987]);
+
+    assert_eq!(periph_0_2.inner().written, [0x1234_5678]);
+}
@@ -1,5 +1,12 @@
-use embedded_hal_compat::ForwardCompat;
+use core::cell::Cell;
+use core::future::Future;
+use core::pin::pin;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use core::task::{Context, Poll};
+use embedded_hal_compat::{ForwardCompat, ForwardPinCompat, NotifierInUse, PinNotifier};
 use futures::executor::block_on;
+use futures::task::{waker, ArcWake};
+use std::sync::Arc;
 
 #[derive(Debug)]
 enum InputPinError {
@@ -62,3 +69,203 @@ fn input_pin_forward() {
     })
     .is_ok());
 }
+
+// An InputPin from embedded-hal 0.2 without a Wait implementation, stepping through levels.
+struct PolledPin {
+    levels: &'static [bool],
+    index: Cell<usize>,
+}
+
+impl PolledPin {
+    fn new(levels: &'static [bool]) -> Self {
+        Self {
+            levels,
+            index: Cell::new(0),
+        }
+    }
+}
+
+impl eh0_2::digital::v2::InputPin for PolledPin {
+    type Error = InputPinError;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        let index = self.index.get();
+        self.index.set(index + 1);
+        Ok(self.levels[index.min(self.levels.len() - 1)])
+    }
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        self.is_high().map(|high| !high)
+    }
+}
+
+struct CountingDelay {
+    total_ns: u64,
+}
+
+impl eh1_0_async::delay::DelayNs for CountingDelay {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.total_ns += ns as u64;
+    }
+}
+
+#[test]
+fn polled_input_pin_forward() {
+    use eh1_0_async::digital::Wait;
+
+    let mut pin = PolledPin::new(&[false, false, true]).forward_polled();
+    assert!(block_on(pin.wait_for_high()).is_ok());
+    assert_eq!(pin.unwrap().release().0.index.get(), 3);
+
+    let mut pin = PolledPin::new(&[true, true, false, true]).forward_polled();
+    assert!(block_on(pin.wait_for_rising_edge()).is_ok());
+    assert_eq!(pin.unwrap().release().0.index.get(), 4);
+
+    let mut pin = PolledPin::new(&[false, true, false]).forward_polled();
+    assert!(block_on(pin.wait_for_falling_edge()).is_ok());
+    assert_eq!(pin.unwrap().release().0.index.get(), 3);
+
+    let mut pin = PolledPin::new(&[true, true, false]).forward_polled();
+    assert!(block_on(pin.wait_for_any_edge()).is_ok());
+    assert!(block_on(pin.wait_for_low()).is_ok());
+}
+
+#[test]
+fn polled_input_pin_interval() {
+    use eh1_0_async::digital::Wait;
+
+    let mut pin = PolledPin::new(&[true, true, true, false])
+        .forward_polled()
+        .with_poll_interval(CountingDelay { total_ns: 0 }, 1_000);
+    assert!(eh1_0::digital::InputPin::is_high(&mut pin).unwrap());
+    assert!(block_on(pin.wait_for_low()).is_ok());
+
+    let (_, delay) = pin.unwrap().release();
+    assert_eq!(delay.total_ns, 2_000);
+}
+
+// An InputPin from embedded-hal 0.2 without a Wait implementation, with an externally set level.
+struct InterruptPin<'a> {
+    level: &'a Cell<bool>,
+}
+
+impl eh0_2::digital::v2::InputPin for InterruptPin<'_> {
+    type Error = InputPinError;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.level.get())
+    }
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        Ok(!self.level.get())
+    }
+}
+
+struct WakeCounter(AtomicUsize);
+
+impl ArcWake for WakeCounter {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn interrupt_input_pin_forward() {
+    use eh1_0_async::digital::Wait;
+
+    static NOTIFIER: PinNotifier = PinNotifier::new();
+
+    let level = Cell::new(false);
+    let mut pin = InterruptPin { level: &level }
+        .forward_interrupt(&NOTIFIER)
+        .unwrap();
+
+    let counter = Arc::new(WakeCounter(AtomicUsize::new(0)));
+    let waker = waker(counter.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    {
+        let mut fut = pin!(pin.wait_for_rising_edge());
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+
+        // Spurious notification without a level change
+        NOTIFIER.notify();
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+
+        level.set(true);
+        NOTIFIER.notify();
+        assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+        assert!(matches!(fut.as_mut().poll(&mut cx), Poll::Ready(Ok(()))));
+    }
+
+    // Level already reached, completes without notification
+    assert!(block_on(pin.wait_for_high()).is_ok());
+
+    {
+        let mut fut = pin!(pin.wait_for_low());
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+        level.set(false);
+        NOTIFIER.notify();
+        assert!(matches!(fut.as_mut().poll(&mut cx), Poll::Ready(Ok(()))));
+    }
+}
+
+#[test]
+fn interrupt_pins_cannot_share_notifier() {
+    use eh1_0_async::digital::Wait;
+
+    static NOTIFIER: PinNotifier = PinNotifier::new();
+    static NOTIFIER_B: PinNotifier = PinNotifier::new();
+
+    let (level_a, level_b) = (Cell::new(false), Cell::new(false));
+    let pin_a = InterruptPin { level: &level_a }
+        .forward_interrupt(&NOTIFIER)
+        .unwrap();
+
+    // A second pin cannot claim the same notifier, and is returned
+    let pin_b = match (InterruptPin { level: &level_b }).forward_interrupt(&NOTIFIER) {
+        Err(NotifierInUse(pin)) => pin,
+        Ok(_) => panic!("notifier claimed twice"),
+    };
+
+    // Releasing the first pin frees the notifier
+    let pin_a = pin_a.unwrap().release();
+    let pin_b = pin_b.forward_interrupt(&NOTIFIER).unwrap();
+    drop(pin_b);
+    let mut pin_a = pin_a.forward_interrupt(&NOTIFIER).unwrap();
+
+    // Pins on a shared interrupt line use a notifier each, notified from the shared handler
+    let mut pin_b = InterruptPin { level: &level_b }
+        .forward_interrupt(&NOTIFIER_B)
+        .unwrap();
+
+    let counter_a = Arc::new(WakeCounter(AtomicUsize::new(0)));
+    let waker_a = waker(counter_a.clone());
+    let counter_b = Arc::new(WakeCounter(AtomicUsize::new(0)));
+    let waker_b = waker(counter_b.clone());
+
+    let mut fut_a = pin!(pin_a.wait_for_high());
+    let mut fut_b = pin!(pin_b.wait_for_high());
+    assert!(fut_a
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker_a))
+        .is_pending());
+    assert!(fut_b
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker_b))
+        .is_pending());
+
+    level_a.set(true);
+    NOTIFIER.notify();
+    NOTIFIER_B.notify();
+    assert_eq!(counter_a.0.load(Ordering::SeqCst), 1);
+    assert_eq!(counter_b.0.load(Ordering::SeqCst), 1);
+
+    assert!(matches!(
+        fut_a.as_mut().poll(&mut Context::from_waker(&waker_a)),
+        Poll::Ready(Ok(()))
+    ));
+    assert!(fut_b
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker_b))
+        .is_pending());
+}